@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// A date format accepted by OpenSearch wherever a `format` for date values can be given.
+///
+/// Prefer the built-in constants over hand-written strings; use [`DateFormat::custom`] for
+/// Java time patterns such as `yyyy-MM-dd`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct DateFormat<'a>(Cow<'a, str>);
+
+impl DateFormat<'static> {
+    /// Milliseconds since the epoch
+    pub const EPOCH_MILLIS: Self = Self(Cow::Borrowed("epoch_millis"));
+    /// Seconds since the epoch
+    pub const EPOCH_SECOND: Self = Self(Cow::Borrowed("epoch_second"));
+    /// A full date with optional time (`yyyy-MM-dd'T'HH:mm:ss.SSSZ` or `yyyy-MM-dd`)
+    pub const DATE_OPTIONAL_TIME: Self = Self(Cow::Borrowed("date_optional_time"));
+    /// Strict variant of [`DateFormat::DATE_OPTIONAL_TIME`]
+    pub const STRICT_DATE_OPTIONAL_TIME: Self = Self(Cow::Borrowed("strict_date_optional_time"));
+    /// A full date (`yyyy-MM-dd`)
+    pub const STRICT_DATE: Self = Self(Cow::Borrowed("strict_date"));
+    /// A full date and time (`yyyy-MM-dd'T'HH:mm:ss.SSSZZ`)
+    pub const STRICT_DATE_TIME: Self = Self(Cow::Borrowed("strict_date_time"));
+    /// A full date and time without milliseconds (`yyyy-MM-dd'T'HH:mm:ssZZ`)
+    pub const STRICT_DATE_TIME_NO_MILLIS: Self = Self(Cow::Borrowed("strict_date_time_no_millis"));
+    /// A basic full date (`yyyyMMdd`)
+    pub const BASIC_DATE: Self = Self(Cow::Borrowed("basic_date"));
+    /// A year and month (`yyyy-MM`)
+    pub const STRICT_YEAR_MONTH: Self = Self(Cow::Borrowed("strict_year_month"));
+    /// A year (`yyyy`)
+    pub const STRICT_YEAR: Self = Self(Cow::Borrowed("strict_year"));
+}
+
+impl<'a> DateFormat<'a> {
+    /// Create a DateFormat from a custom pattern, e.g. `yyyy-MM-dd HH:mm`
+    pub fn custom(pattern: impl Into<Cow<'a, str>>) -> Self {
+        Self(pattern.into())
+    }
+
+    /// Combine with another format, accepting either when parsing (`a||b`)
+    pub fn or(self, other: DateFormat<'_>) -> DateFormat<'static> {
+        DateFormat(Cow::Owned(format!("{}||{}", self.0, other.0)))
+    }
+
+    /// The format string as sent to OpenSearch
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> DateFormat<'static> {
+        DateFormat(Cow::Owned(self.0.to_string()))
+    }
+}
+
+impl<'a> Display for DateFormat<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> From<&'a str> for DateFormat<'a> {
+    fn from(pattern: &'a str) -> Self {
+        Self::custom(pattern)
+    }
+}
+
+impl From<String> for DateFormat<'static> {
+    fn from(pattern: String) -> Self {
+        Self::custom(pattern)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn test_date_format_constants() {
+    assert_eq!(DateFormat::EPOCH_MILLIS.as_str(), "epoch_millis");
+    assert_eq!(DateFormat::EPOCH_SECOND.as_str(), "epoch_second");
+    assert_eq!(
+        DateFormat::DATE_OPTIONAL_TIME.as_str(),
+        "date_optional_time"
+    );
+    assert_eq!(
+        DateFormat::STRICT_DATE_OPTIONAL_TIME.as_str(),
+        "strict_date_optional_time"
+    );
+    assert_eq!(DateFormat::STRICT_DATE.as_str(), "strict_date");
+    assert_eq!(DateFormat::STRICT_DATE_TIME.as_str(), "strict_date_time");
+    assert_eq!(
+        DateFormat::STRICT_DATE_TIME_NO_MILLIS.as_str(),
+        "strict_date_time_no_millis"
+    );
+    assert_eq!(DateFormat::BASIC_DATE.as_str(), "basic_date");
+    assert_eq!(DateFormat::STRICT_YEAR_MONTH.as_str(), "strict_year_month");
+    assert_eq!(DateFormat::STRICT_YEAR.as_str(), "strict_year");
+}
+
+#[test]
+fn test_date_format_custom_and_combined() {
+    assert_eq!(DateFormat::custom("yyyy-MM-dd").to_string(), "yyyy-MM-dd");
+    assert_eq!(
+        DateFormat::STRICT_DATE_OPTIONAL_TIME
+            .or(DateFormat::EPOCH_MILLIS)
+            .as_str(),
+        "strict_date_optional_time||epoch_millis"
+    );
+    assert_eq!(
+        serde_json::to_value(DateFormat::EPOCH_MILLIS).unwrap(),
+        serde_json::json!("epoch_millis")
+    );
+}
+
+#[test]
+fn test_range_query_with_date_format() {
+    use crate::{RangeQuery, ToOpenSearchJson};
+
+    let query = RangeQuery::new("created_at")
+        .gte("2024-01-01")
+        .format(DateFormat::STRICT_DATE);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "range": {
+                "created_at": {
+                    "gte": "2024-01-01",
+                    "format": "strict_date"
+                }
+            }
+        })
+    );
+}
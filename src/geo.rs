@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// A unit of distance accepted by the geo queries and sorts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DistanceUnit {
+    /// Miles (`mi`)
+    #[serde(rename = "mi")]
+    Miles,
+    /// Yards (`yd`)
+    #[serde(rename = "yd")]
+    Yards,
+    /// Feet (`ft`)
+    #[serde(rename = "ft")]
+    Feet,
+    /// Inches (`in`)
+    #[serde(rename = "in")]
+    Inches,
+    /// Kilometers (`km`)
+    #[serde(rename = "km")]
+    Kilometers,
+    /// Meters (`m`)
+    #[serde(rename = "m")]
+    Meters,
+    /// Centimeters (`cm`)
+    #[serde(rename = "cm")]
+    Centimeters,
+    /// Millimeters (`mm`)
+    #[serde(rename = "mm")]
+    Millimeters,
+    /// Nautical miles (`nmi`)
+    #[serde(rename = "nmi")]
+    NauticalMiles,
+}
+
+impl DistanceUnit {
+    /// The unit suffix as sent to OpenSearch
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::Yards => "yd",
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Inches => "in",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Meters => "m",
+            DistanceUnit::Centimeters => "cm",
+            DistanceUnit::Millimeters => "mm",
+            DistanceUnit::NauticalMiles => "nmi",
+        }
+    }
+
+    /// Parse a unit as accepted by OpenSearch, either the suffix (`km`) or the long name
+    /// (`kilometers`)
+    pub fn parse(unit: &str) -> Option<Self> {
+        match unit {
+            "mi" | "miles" => Some(DistanceUnit::Miles),
+            "yd" | "yards" => Some(DistanceUnit::Yards),
+            "ft" | "feet" => Some(DistanceUnit::Feet),
+            "in" | "inch" => Some(DistanceUnit::Inches),
+            "km" | "kilometers" => Some(DistanceUnit::Kilometers),
+            "m" | "meters" => Some(DistanceUnit::Meters),
+            "cm" | "centimeters" => Some(DistanceUnit::Centimeters),
+            "mm" | "millimeters" => Some(DistanceUnit::Millimeters),
+            "nmi" | "NM" | "nauticalmiles" => Some(DistanceUnit::NauticalMiles),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DistanceUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<DistanceUnit> for Cow<'static, str> {
+    fn from(unit: DistanceUnit) -> Self {
+        Cow::Borrowed(unit.as_str())
+    }
+}
+
+/// A distance with a unit, rendered as e.g. `12km`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance {
+    /// The amount of the unit
+    pub value: f64,
+    /// The unit of the distance
+    pub unit: DistanceUnit,
+}
+
+impl Distance {
+    /// Create a new Distance
+    pub fn new(value: f64, unit: DistanceUnit) -> Self {
+        Self { value, unit }
+    }
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl From<Distance> for Cow<'static, str> {
+    fn from(distance: Distance) -> Self {
+        Cow::Owned(distance.to_string())
+    }
+}
+
+/// A latitude and longitude
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GeoPoint {
+    /// The latitude
+    pub lat: f64,
+    /// The longitude
+    pub lon: f64,
+}
+
+impl GeoPoint {
+    /// Create a new GeoPoint
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+}
+
+impl ToOpenSearchJson for GeoPoint {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("lat".to_string(), self.lat.into());
+        result.insert("lon".to_string(), self.lon.into());
+        Value::Object(result)
+    }
+}
+
+#[cfg(test)]
+mod test;
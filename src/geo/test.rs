@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn test_distance_unit_rendering() {
+    let cases = [
+        (DistanceUnit::Miles, "12mi"),
+        (DistanceUnit::Yards, "12yd"),
+        (DistanceUnit::Feet, "12ft"),
+        (DistanceUnit::Inches, "12in"),
+        (DistanceUnit::Kilometers, "12km"),
+        (DistanceUnit::Meters, "12m"),
+        (DistanceUnit::Centimeters, "12cm"),
+        (DistanceUnit::Millimeters, "12mm"),
+        (DistanceUnit::NauticalMiles, "12nmi"),
+    ];
+
+    for (unit, expected) in cases {
+        let distance: Cow<str> = Distance::new(12.0, unit).into();
+        assert_eq!(distance, expected);
+        assert_eq!(DistanceUnit::parse(unit.as_str()), Some(unit));
+        assert_eq!(
+            serde_json::to_value(unit).unwrap(),
+            Value::String(unit.to_string())
+        );
+    }
+}
+
+#[test]
+fn test_fractional_distance() {
+    assert_eq!(
+        Distance::new(1.5, DistanceUnit::Kilometers).to_string(),
+        "1.5km"
+    );
+}
@@ -7,9 +7,16 @@ pub trait ToOpenSearchJson {
     fn to_json(&self) -> serde_json::Value;
 }
 
+mod date_format;
+mod geo;
 mod query;
 mod request;
 mod util;
 
+pub use date_format::*;
+pub use geo::*;
 pub use query::*;
 pub use request::*;
+
+#[cfg(test)]
+mod test;
@@ -3,21 +3,46 @@ use std::borrow::Cow;
 use serde::Serialize;
 
 mod bool;
+mod constant_score;
+mod cost;
+mod dis_max;
 mod function_score;
+mod geo_distance;
+mod has_child;
+mod has_parent;
+mod map_fields;
+mod match_all;
 mod match_phrase;
 mod match_phrase_prefix;
 mod match_query;
+mod minimum_should_match;
+mod multi_match;
+mod nested;
+mod query_string;
 mod range;
 mod regexp;
+mod summary;
 mod term;
 mod terms;
+mod walk;
 mod wildcard;
 
 pub use bool::*;
+pub use constant_score::*;
+pub use cost::*;
+pub use dis_max::*;
 pub use function_score::*;
+pub use geo_distance::*;
+pub use has_child::*;
+pub use has_parent::*;
+pub use match_all::*;
 pub use match_phrase::*;
 pub use match_phrase_prefix::*;
 pub use match_query::*;
+pub use minimum_should_match::*;
+pub use multi_match::*;
+pub use nested::*;
+pub use query_string::*;
 pub use range::*;
 pub use regexp::*;
 use serde_json::Value;
@@ -25,22 +50,41 @@ pub use term::*;
 pub use terms::*;
 pub use wildcard::*;
 
-use crate::ToOpenSearchJson;
+use crate::{SearchRequest, ToOpenSearchJson, ValidationError, require_finite};
 
 /// Enum representing the different types of queries that can be used in a search request.
-#[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type", content = "params")]
+#[derive(Debug, Clone)]
 pub enum QueryType<'a> {
     /// Bool query
     Bool(BoolQuery<'a>),
+    /// Constant score query
+    ConstantScore(ConstantScoreQuery<'a>),
+    /// Disjunction max query
+    DisMax(DisMaxQuery<'a>),
     /// Function score query
     FunctionScore(FunctionScoreQuery<'a>),
+    /// Geo distance query
+    GeoDistance(GeoDistanceQuery<'a>),
+    /// Has child query
+    HasChild(HasChildQuery<'a>),
+    /// Has parent query
+    HasParent(HasParentQuery<'a>),
+    /// Match all query
+    MatchAll(MatchAllQuery),
+    /// Match none query
+    MatchNone,
     /// Match phrase query
     MatchPhrase(MatchPhraseQuery<'a>),
     /// Match phrase prefix query
     MatchPhrasePrefix(MatchPhrasePrefixQuery<'a>),
     /// Match query
     Match(MatchQuery<'a>),
+    /// Multi match query
+    MultiMatch(MultiMatchQuery<'a>),
+    /// Nested query
+    Nested(NestedQuery<'a>),
+    /// Query string query
+    QueryString(QueryStringQuery<'a>),
     /// Range query
     Range(RangeQuery<'a>),
     /// Regexp query
@@ -51,26 +95,124 @@ pub enum QueryType<'a> {
     Terms(TermsQuery<'a>),
     /// Wildcard query
     WildCard(WildcardQuery<'a>),
+    /// Prebuilt query JSON, emitted as-is
+    Raw(Value),
 }
 
 impl<'a> ToOpenSearchJson for QueryType<'a> {
     fn to_json(&self) -> Value {
         match self {
             QueryType::Bool(bool_query) => bool_query.to_json(),
+            QueryType::ConstantScore(constant_score) => constant_score.to_json(),
+            QueryType::DisMax(dis_max) => dis_max.to_json(),
             QueryType::FunctionScore(function_score) => function_score.to_json(),
+            QueryType::GeoDistance(geo_distance) => geo_distance.to_json(),
+            QueryType::HasChild(has_child) => has_child.to_json(),
+            QueryType::HasParent(has_parent) => has_parent.to_json(),
+            QueryType::MatchAll(match_all) => match_all.to_json(),
+            QueryType::MatchNone => serde_json::json!({"match_none": {}}),
             QueryType::MatchPhrase(match_phrase) => match_phrase.to_json(),
             QueryType::MatchPhrasePrefix(match_phrase_prefix) => match_phrase_prefix.to_json(),
             QueryType::Match(match_query) => match_query.to_json(),
+            QueryType::MultiMatch(multi_match) => multi_match.to_json(),
+            QueryType::QueryString(query_string) => query_string.to_json(),
             QueryType::Term(term) => term.to_json(),
             QueryType::Terms(terms) => terms.to_json(),
+            QueryType::Nested(nested) => nested.to_json(),
             QueryType::Range(range) => range.to_json(),
             QueryType::WildCard(wildcard_query) => wildcard_query.to_json(),
             QueryType::Regexp(regexp_query) => regexp_query.to_json(),
+            QueryType::Raw(raw) => raw.clone(),
         }
     }
 }
 
+/// Serializes to the same OpenSearch JSON as [`ToOpenSearchJson::to_json`].
+impl<'a> Serialize for QueryType<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
 impl<'a> QueryType<'a> {
+    /// Convenience method for creating a match all query
+    pub fn match_all() -> Self {
+        QueryType::MatchAll(MatchAllQuery::new())
+    }
+
+    /// Convenience method for creating a constant score query
+    pub fn constant_score(filter: QueryType<'a>) -> Self {
+        QueryType::ConstantScore(ConstantScoreQuery::new(filter))
+    }
+
+    /// Convenience method for matching every document that passes the given filters, a bool
+    /// query with `match_all` as `must` and the filters as `filter`
+    pub fn filtered(filters: impl IntoIterator<Item = QueryType<'a>>) -> Self {
+        let mut bool_query = BoolQuery::new().must(QueryType::match_all());
+        bool_query.filter.to_mut().extend(filters);
+        QueryType::Bool(bool_query)
+    }
+
+    /// Type-ahead query: a bool `should` of `match_phrase_prefix` clauses, one per field with
+    /// its boost, where at least one clause has to match.
+    pub fn autocomplete(
+        text: impl Into<Cow<'a, str>>,
+        fields_with_boosts: &[(&'a str, f64)],
+    ) -> Self {
+        let text = text.into();
+        let bool_query = fields_with_boosts.iter().fold(
+            BoolQuery::new().minimum_should_match(1),
+            |bool_query, &(field, boost)| {
+                bool_query.should(QueryType::MatchPhrasePrefix(
+                    MatchPhrasePrefixQuery::new(field, text.clone()).boost(boost),
+                ))
+            },
+        );
+        QueryType::Bool(bool_query)
+    }
+
+    /// Forgiving search-box query: a `best_fields` multi match over `fields` with the given
+    /// fuzziness, where every term has to match.
+    pub fn fuzzy_search<I>(
+        text: impl Into<Cow<'a, str>>,
+        fields: I,
+        fuzziness: impl Into<Cow<'a, str>>,
+    ) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        QueryType::MultiMatch(
+            MultiMatchQuery::new(text, fields)
+                .multi_match_type(MultiMatchType::BestFields)
+                .fuzziness(fuzziness)
+                .operator("and"),
+        )
+    }
+
+    /// Wrap the query in a bool filter clause, `{"bool": {"filter": [query]}}`.
+    ///
+    /// Filter context disables scoring for the wrapped query, so every match scores 0. Use
+    /// [`QueryType::constant_score`] instead when matches need a non-zero score.
+    pub fn in_filter_context(self) -> Self {
+        QueryType::Bool(BoolQuery::new().filter(self))
+    }
+
+    /// Wrap the query in a new SearchRequest, e.g. `QueryType::term("a", "b").into_request()`
+    pub fn into_request(self) -> SearchRequest<'a> {
+        SearchRequest::new().query(self)
+    }
+
+    /// Convenience method for creating a nested query
+    pub fn nested(path: impl Into<Cow<'a, str>>, query: QueryType<'a>) -> Self {
+        QueryType::Nested(NestedQuery::new(path, query))
+    }
+
+    /// Convenience method for creating a match none query
+    pub fn match_none() -> Self {
+        QueryType::MatchNone
+    }
+
     /// Convenience method for creating a term query
     pub fn term<T: Into<Value>>(field: impl Into<Cow<'a, str>>, value: T) -> Self {
         QueryType::Term(TermQuery::new(field, value))
@@ -84,6 +226,16 @@ impl<'a> QueryType<'a> {
         QueryType::Terms(TermsQuery::new(field, values))
     }
 
+    /// Convenience method for creating a terms query borrowing its values instead of copying them
+    pub fn terms_ref(field: impl Into<Cow<'a, str>>, values: &'a [Value]) -> Self {
+        QueryType::Terms(TermsQuery::borrowed(field, values))
+    }
+
+    /// Convenience method for creating a terms query that fetches its values from another document
+    pub fn terms_lookup(field: impl Into<Cow<'a, str>>, lookup: TermsLookup<'a>) -> Self {
+        QueryType::Terms(TermsQuery::lookup(field, lookup))
+    }
+
     /// Convenience method for creating a wildcard query
     pub fn wildcard(
         field: impl Into<Cow<'a, str>>,
@@ -111,38 +263,195 @@ impl<'a> QueryType<'a> {
         QueryType::MatchPhrasePrefix(MatchPhrasePrefixQuery::new(field, query))
     }
 
+    /// Convenience method for creating a multi match query
+    pub fn multi_match<I>(query: impl Into<Cow<'a, str>>, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        QueryType::MultiMatch(MultiMatchQuery::new(query, fields))
+    }
+
+    /// Convenience method for creating a query from prebuilt JSON
+    pub fn raw(query: impl Into<Value>) -> Self {
+        QueryType::Raw(query.into())
+    }
+
+    /// Convenience method for creating a query string query
+    pub fn query_string(query: impl Into<Cow<'a, str>>) -> Self {
+        QueryType::QueryString(QueryStringQuery::new(query))
+    }
+
     /// Convenience method for starting a bool query
     pub fn bool_query() -> BoolQueryBuilder<'a> {
         BoolQueryBuilder::new()
     }
 
+    /// Convenience method for starting a dis max query
+    pub fn dis_max() -> DisMaxQueryBuilder<'a> {
+        DisMaxQueryBuilder::new()
+    }
+
     /// Convenience method for starting a match query
     pub fn range(field: impl Into<Cow<'a, str>>) -> RangeQueryBuilder<'a> {
         RangeQueryBuilder::new(field)
     }
 
+    /// Convenience method for creating a range query bounded on both sides (inclusive)
+    pub fn range_between<T: Into<Value>, U: Into<Value>>(
+        field: impl Into<Cow<'a, str>>,
+        gte: T,
+        lte: U,
+    ) -> Self {
+        QueryType::Range(RangeQuery::new(field).gte(gte).lte(lte))
+    }
+
+    /// Convenience method for creating a range query with only a lower bound (inclusive)
+    pub fn range_gte<T: Into<Value>>(field: impl Into<Cow<'a, str>>, value: T) -> Self {
+        QueryType::Range(RangeQuery::new(field).gte(value))
+    }
+
+    /// Convenience method for creating a range query with only an upper bound (inclusive)
+    pub fn range_lte<T: Into<Value>>(field: impl Into<Cow<'a, str>>, value: T) -> Self {
+        QueryType::Range(RangeQuery::new(field).lte(value))
+    }
+
     /// Convenience method for starting a function score query
     pub fn function_score() -> FunctionScoreQueryBuilder<'a> {
         FunctionScoreQueryBuilder::new()
     }
 
+    /// The boost set on the query, `None` when unset or when the query type has no boost
+    pub fn boost(&self) -> Option<f64> {
+        match self {
+            QueryType::Bool(bool_query) => bool_query.boost,
+            QueryType::ConstantScore(constant_score) => constant_score.boost,
+            QueryType::DisMax(dis_max) => dis_max.boost,
+            QueryType::FunctionScore(function_score) => function_score.boost,
+            QueryType::GeoDistance(geo_distance) => geo_distance.boost,
+            QueryType::HasChild(has_child) => has_child.boost,
+            QueryType::HasParent(has_parent) => has_parent.boost,
+            QueryType::MatchAll(match_all) => match_all.boost,
+            QueryType::MatchPhrase(match_phrase) => match_phrase.boost,
+            QueryType::MatchPhrasePrefix(match_phrase_prefix) => match_phrase_prefix.boost,
+            QueryType::Match(match_query) => match_query.boost,
+            QueryType::MultiMatch(multi_match) => multi_match.boost,
+            QueryType::Nested(nested) => nested.boost,
+            QueryType::QueryString(query_string) => query_string.boost,
+            QueryType::Range(range) => range.boost,
+            QueryType::Term(term) => term.boost,
+            QueryType::Terms(terms) => terms.boost,
+            QueryType::WildCard(wildcard) => wildcard.boost_value(),
+            QueryType::MatchNone | QueryType::Regexp(_) | QueryType::Raw(_) => None,
+        }
+    }
+
+    /// Whether [`QueryType::with_boost`] can set a boost on this query type
+    pub(crate) fn supports_boost(&self) -> bool {
+        !matches!(
+            self,
+            QueryType::MatchNone | QueryType::Regexp(_) | QueryType::Raw(_)
+        )
+    }
+
+    /// Set the boost on any query type that supports one.
+    ///
+    /// Query types without a boost are returned unchanged, and trip a debug assertion so the
+    /// mistake is caught in development.
+    pub fn with_boost(self, boost: f64) -> QueryType<'a> {
+        match self {
+            QueryType::Bool(bool_query) => QueryType::Bool(bool_query.boost(boost)),
+            QueryType::ConstantScore(constant_score) => {
+                QueryType::ConstantScore(constant_score.boost(boost))
+            }
+            QueryType::DisMax(dis_max) => QueryType::DisMax(dis_max.boost(boost)),
+            QueryType::FunctionScore(function_score) => {
+                QueryType::FunctionScore(function_score.boost(boost))
+            }
+            QueryType::GeoDistance(geo_distance) => {
+                QueryType::GeoDistance(geo_distance.boost(boost))
+            }
+            QueryType::HasChild(has_child) => QueryType::HasChild(has_child.boost(boost)),
+            QueryType::HasParent(has_parent) => QueryType::HasParent(has_parent.boost(boost)),
+            QueryType::MatchAll(match_all) => QueryType::MatchAll(match_all.boost(boost)),
+            QueryType::MatchPhrase(match_phrase) => {
+                QueryType::MatchPhrase(match_phrase.boost(boost))
+            }
+            QueryType::MatchPhrasePrefix(match_phrase_prefix) => {
+                QueryType::MatchPhrasePrefix(match_phrase_prefix.boost(boost))
+            }
+            QueryType::Match(match_query) => QueryType::Match(match_query.boost(boost)),
+            QueryType::MultiMatch(multi_match) => QueryType::MultiMatch(multi_match.boost(boost)),
+            QueryType::QueryString(query_string) => {
+                QueryType::QueryString(query_string.boost(boost))
+            }
+            QueryType::Nested(nested) => QueryType::Nested(nested.boost(boost)),
+            QueryType::Range(range) => QueryType::Range(range.boost(boost)),
+            QueryType::Term(term) => QueryType::Term(term.boost(boost)),
+            QueryType::Terms(terms) => QueryType::Terms(terms.boost(boost)),
+            QueryType::WildCard(wildcard) => QueryType::WildCard(wildcard.boost(boost)),
+            QueryType::MatchNone | QueryType::Regexp(_) | QueryType::Raw(_) => {
+                debug_assert!(false, "with_boost called on a query without a boost");
+                self
+            }
+        }
+    }
+
+    /// Like [`QueryType::with_boost`], but rejects a NaN or infinite boost and query types
+    /// without a boost instead of ignoring them
+    pub fn try_with_boost(self, boost: f64) -> Result<QueryType<'a>, ValidationError> {
+        let boost = require_finite("boost", boost)?;
+        if !self.supports_boost() {
+            return Err(ValidationError::BoostUnsupported {
+                query: self.kind_name(),
+            });
+        }
+        Ok(self.with_boost(boost))
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> QueryType<'static> {
         match self {
             QueryType::Bool(bool_query) => QueryType::Bool(bool_query.to_owned()),
+            QueryType::ConstantScore(constant_score) => {
+                QueryType::ConstantScore(constant_score.to_owned())
+            }
+            QueryType::DisMax(dis_max) => QueryType::DisMax(dis_max.to_owned()),
             QueryType::FunctionScore(function_score) => {
                 QueryType::FunctionScore(function_score.to_owned())
             }
+            QueryType::GeoDistance(geo_distance) => QueryType::GeoDistance(geo_distance.to_owned()),
+            QueryType::HasChild(has_child) => QueryType::HasChild(has_child.to_owned()),
+            QueryType::HasParent(has_parent) => QueryType::HasParent(has_parent.to_owned()),
+            QueryType::MatchAll(match_all) => QueryType::MatchAll(match_all.clone()),
+            QueryType::MatchNone => QueryType::MatchNone,
             QueryType::MatchPhrase(match_phrase) => QueryType::MatchPhrase(match_phrase.to_owned()),
             QueryType::MatchPhrasePrefix(match_phrase_prefix) => {
                 QueryType::MatchPhrasePrefix(match_phrase_prefix.to_owned())
             }
             QueryType::Match(match_query) => QueryType::Match(match_query.to_owned()),
+            QueryType::MultiMatch(multi_match) => QueryType::MultiMatch(multi_match.to_owned()),
+            QueryType::QueryString(query_string) => QueryType::QueryString(query_string.to_owned()),
+            QueryType::Nested(nested) => QueryType::Nested(nested.to_owned()),
             QueryType::Range(range) => QueryType::Range(range.to_owned()),
             QueryType::Regexp(regexp) => QueryType::Regexp(regexp.to_owned()),
             QueryType::Term(term) => QueryType::Term(term.to_owned()),
             QueryType::Terms(terms) => QueryType::Terms(terms.to_owned()),
             QueryType::WildCard(wildcard) => QueryType::WildCard(wildcard.to_owned()),
+            QueryType::Raw(raw) => QueryType::Raw(raw.clone()),
+        }
+    }
+
+    /// Borrow the query JSON when this query already holds it prebuilt.
+    ///
+    /// Returns `None` for queries that have to be serialized with `to_json`.
+    pub fn as_cached_json(&self) -> Option<&Value> {
+        match self {
+            QueryType::Raw(raw) => Some(raw),
+            _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod test;
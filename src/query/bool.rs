@@ -1,9 +1,22 @@
-use crate::util::is_empty_slice;
+use crate::util::{finite, is_empty_slice};
 use serde::Serialize;
 use serde_json::{Map, Value};
 use std::borrow::Cow;
 
-use crate::{QueryType, ToOpenSearchJson};
+use crate::{MinimumShouldMatch, QueryType, ToOpenSearchJson};
+
+/// The kind of clause a query occupies in a bool query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClauseKind {
+    /// Must clause
+    Must,
+    /// Must not clause
+    MustNot,
+    /// Should clause
+    Should,
+    /// Filter clause
+    Filter,
+}
 
 /// Bool Query
 #[derive(Default, Debug, Clone, Serialize)]
@@ -22,7 +35,7 @@ pub struct BoolQuery<'a> {
     pub filter: Cow<'a, [QueryType<'a>]>,
     /// Minimum should match
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minimum_should_match: Option<i32>,
+    pub minimum_should_match: Option<MinimumShouldMatch>,
     /// Boost
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
@@ -58,9 +71,41 @@ impl<'a> BoolQuery<'a> {
         self
     }
 
+    /// Add a must query if there is one, leaving the query unchanged for `None`
+    pub fn must_opt(self, query: Option<QueryType<'a>>) -> Self {
+        match query {
+            Some(query) => self.must(query),
+            None => self,
+        }
+    }
+
+    /// Add a must not query if there is one, leaving the query unchanged for `None`
+    pub fn must_not_opt(self, query: Option<QueryType<'a>>) -> Self {
+        match query {
+            Some(query) => self.must_not(query),
+            None => self,
+        }
+    }
+
+    /// Add a should query if there is one, leaving the query unchanged for `None`
+    pub fn should_opt(self, query: Option<QueryType<'a>>) -> Self {
+        match query {
+            Some(query) => self.should(query),
+            None => self,
+        }
+    }
+
+    /// Add a filter query if there is one, leaving the query unchanged for `None`
+    pub fn filter_opt(self, query: Option<QueryType<'a>>) -> Self {
+        match query {
+            Some(query) => self.filter(query),
+            None => self,
+        }
+    }
+
     /// Set the minimum should match
-    pub fn minimum_should_match(mut self, min: i32) -> Self {
-        self.minimum_should_match = Some(min);
+    pub fn minimum_should_match(mut self, min: impl Into<MinimumShouldMatch>) -> Self {
+        self.minimum_should_match = Some(min.into());
         self
     }
 
@@ -70,6 +115,64 @@ impl<'a> BoolQuery<'a> {
         self
     }
 
+    /// Rescale the boosts of the direct should clauses so they sum to 1.0
+    pub fn normalize_boosts(&mut self) {
+        self.normalize_boosts_to(1.0);
+    }
+
+    /// Rescale the boosts of the direct should clauses so they sum to `total`.
+    ///
+    /// Clauses without a boost count as 1.0, clauses whose query type has no boost are skipped.
+    pub fn normalize_boosts_to(&mut self, total: f64) {
+        let sum: f64 = self
+            .should
+            .iter()
+            .filter(|q| q.supports_boost())
+            .map(|q| q.boost().unwrap_or(1.0))
+            .sum();
+        if sum <= 0.0 {
+            return;
+        }
+
+        for clause in self.should.to_mut().iter_mut() {
+            if !clause.supports_boost() {
+                continue;
+            }
+            let boost = clause.boost().unwrap_or(1.0) * total / sum;
+            let query = std::mem::replace(clause, QueryType::MatchNone);
+            *clause = query.with_boost(boost);
+        }
+    }
+
+    /// Whether the bool query has no clauses, minimum should match or boost
+    pub fn is_empty(&self) -> bool {
+        self.clause_count() == 0 && self.minimum_should_match.is_none() && self.boost.is_none()
+    }
+
+    /// The total number of must, must not, should and filter clauses
+    pub fn clause_count(&self) -> usize {
+        self.must.len() + self.must_not.len() + self.should.len() + self.filter.len()
+    }
+
+    /// Iterate over every clause, tagged with its kind
+    pub fn clauses(&self) -> impl Iterator<Item = (ClauseKind, &QueryType<'a>)> {
+        let tag = |kind| move |query| (kind, query);
+        self.must
+            .iter()
+            .map(tag(ClauseKind::Must))
+            .chain(self.must_not.iter().map(tag(ClauseKind::MustNot)))
+            .chain(self.should.iter().map(tag(ClauseKind::Should)))
+            .chain(self.filter.iter().map(tag(ClauseKind::Filter)))
+    }
+
+    /// Keep only the clauses for which `f` returns true
+    pub fn retain(&mut self, mut f: impl FnMut(ClauseKind, &QueryType<'a>) -> bool) {
+        self.must.to_mut().retain(|q| f(ClauseKind::Must, q));
+        self.must_not.to_mut().retain(|q| f(ClauseKind::MustNot, q));
+        self.should.to_mut().retain(|q| f(ClauseKind::Should, q));
+        self.filter.to_mut().retain(|q| f(ClauseKind::Filter, q));
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> BoolQuery<'static> {
         BoolQuery {
@@ -77,7 +180,7 @@ impl<'a> BoolQuery<'a> {
             must_not: Cow::Owned(self.must_not.iter().map(|q| q.to_owned()).collect()),
             should: Cow::Owned(self.should.iter().map(|q| q.to_owned()).collect()),
             filter: Cow::Owned(self.filter.iter().map(|q| q.to_owned()).collect()),
-            minimum_should_match: self.minimum_should_match,
+            minimum_should_match: self.minimum_should_match.clone(),
             boost: self.boost,
         }
     }
@@ -113,14 +216,11 @@ impl<'a> ToOpenSearchJson for BoolQuery<'a> {
             bool_obj.insert("filter".to_string(), Value::Array(filter_queries));
         }
 
-        if let Some(min) = self.minimum_should_match {
-            bool_obj.insert(
-                "minimum_should_match".to_string(),
-                Value::Number(min.into()),
-            );
+        if let Some(ref min) = self.minimum_should_match {
+            bool_obj.insert("minimum_should_match".to_string(), min.into());
         }
 
-        if let Some(boost) = self.boost {
+        if let Some(boost) = self.boost.and_then(finite) {
             bool_obj.insert("boost".to_string(), boost.into());
         }
 
@@ -137,7 +237,7 @@ pub struct BoolQueryBuilder<'a> {
     must_not: Cow<'a, [QueryType<'a>]>,
     should: Cow<'a, [QueryType<'a>]>,
     filter: Cow<'a, [QueryType<'a>]>,
-    minimum_should_match: Option<i32>,
+    minimum_should_match: Option<MinimumShouldMatch>,
     boost: Option<f64>,
 }
 
@@ -171,9 +271,19 @@ impl<'a> BoolQueryBuilder<'a> {
         self
     }
 
+    /// Add should queries and require at least `min` of them to match
+    pub fn should_with_min(
+        &mut self,
+        queries: impl IntoIterator<Item = QueryType<'a>>,
+        min: i32,
+    ) -> &mut Self {
+        self.should.to_mut().extend(queries);
+        self.minimum_should_match(min)
+    }
+
     /// Set the minimum should match
-    pub fn minimum_should_match(&mut self, min: i32) -> &mut Self {
-        self.minimum_should_match = Some(min);
+    pub fn minimum_should_match(&mut self, min: impl Into<MinimumShouldMatch>) -> &mut Self {
+        self.minimum_should_match = Some(min.into());
         self
     }
 
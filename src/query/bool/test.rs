@@ -53,3 +53,152 @@ fn test_bool_query_builder() {
         })
     );
 }
+
+#[test]
+fn test_is_empty_and_clause_count() {
+    let empty = BoolQuery::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.clause_count(), 0);
+    assert!(!BoolQuery::new().boost(2.0).is_empty());
+
+    let populated = BoolQuery::new()
+        .must(QueryType::term("a", "a"))
+        .filter(QueryType::term("b", "b"))
+        .filter(QueryType::term("c", "c"));
+    assert!(!populated.is_empty());
+    assert_eq!(populated.clause_count(), 3);
+}
+
+#[test]
+fn test_clauses_and_retain() {
+    let mut query = BoolQuery::new()
+        .must(QueryType::term("a", "a"))
+        .should(QueryType::term("b", "b"))
+        .should(QueryType::term("c", "c"))
+        .filter(QueryType::term("d", "d"));
+
+    let kinds: Vec<ClauseKind> = query.clauses().map(|(kind, _)| kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            ClauseKind::Must,
+            ClauseKind::Should,
+            ClauseKind::Should,
+            ClauseKind::Filter
+        ]
+    );
+
+    query.retain(|kind, _| kind != ClauseKind::Should);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "bool": {
+                "must": [{"term": {"a": "a"}}],
+                "filter": [{"term": {"d": "d"}}]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_normalize_boosts() {
+    let mut query = BoolQuery::new()
+        .must(QueryType::term("a", "a").with_boost(4.0))
+        .should(QueryType::term("b", "b").with_boost(2.0))
+        .should(QueryType::term("c", "c").with_boost(3.0))
+        .should(QueryType::term("d", "d").with_boost(5.0))
+        .should(QueryType::regexp("e", "e.*"));
+
+    query.normalize_boosts();
+
+    let boosts: Vec<f64> = query.should.iter().filter_map(|q| q.boost()).collect();
+    assert_eq!(boosts.len(), 3);
+    assert!((boosts.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((boosts[0] - 0.2).abs() < 1e-9);
+    assert!((boosts[1] - 0.3).abs() < 1e-9);
+    assert!((boosts[2] - 0.5).abs() < 1e-9);
+    assert_eq!(query.must[0].boost(), Some(4.0));
+}
+
+#[test]
+fn test_opt_clauses() {
+    let status: Option<&str> = None;
+    let query = BoolQuery::new()
+        .must(QueryType::term("type", "post"))
+        .must_opt(status.map(|status| QueryType::term("status", status)));
+    assert_eq!(
+        query.to_json(),
+        BoolQuery::new()
+            .must(QueryType::term("type", "post"))
+            .to_json()
+    );
+
+    let query = BoolQuery::new()
+        .must_opt(Some(QueryType::term("type", "post")))
+        .must_not_opt(Some(QueryType::term("deleted", true)))
+        .should_opt(None)
+        .filter_opt(Some(QueryType::term("status", "published")));
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "bool": {
+                "must": [{ "term": { "type": "post" } }],
+                "must_not": [{ "term": { "deleted": true } }],
+                "filter": [{ "term": { "status": "published" } }]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_bool_boost_serializes_as_number() {
+    let query = BoolQuery::new()
+        .must(QueryType::term("status", "active"))
+        .boost(1.5);
+    let json = query.to_json();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "bool": {
+                "must": [{ "term": { "status": "active" } }],
+                "boost": 1.5
+            }
+        })
+    );
+    assert!(json["bool"]["boost"].is_f64());
+    assert_eq!(
+        serde_json::to_value(QueryType::Bool(query)).unwrap()["bool"]["boost"],
+        1.5
+    );
+}
+
+#[test]
+fn test_builder_should_with_min() {
+    let mut builder = BoolQueryBuilder::new();
+    builder.should_with_min(
+        [
+            QueryType::term("tags", "rust"),
+            QueryType::term("tags", "search"),
+            QueryType::term("tags", "opensearch"),
+        ],
+        2,
+    );
+    let query = builder.build();
+
+    assert_eq!(query.should.len(), 3);
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "bool": {
+                "should": [
+                    { "term": { "tags": "rust" } },
+                    { "term": { "tags": "search" } },
+                    { "term": { "tags": "opensearch" } }
+                ],
+                "minimum_should_match": 2
+            }
+        })
+    );
+}
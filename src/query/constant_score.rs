@@ -0,0 +1,64 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Constant Score Query
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstantScoreQuery<'a> {
+    /// The filter query, every matching document gets the same score
+    #[serde(borrow)]
+    pub filter: Box<QueryType<'a>>,
+    /// The boost value, used as the constant score
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> ConstantScoreQuery<'a> {
+    /// Create a new ConstantScoreQuery wrapping the given filter
+    pub fn new(filter: QueryType<'a>) -> Self {
+        Self {
+            filter: Box::new(filter),
+            boost: None,
+        }
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> ConstantScoreQuery<'static> {
+        ConstantScoreQuery {
+            filter: Box::new((*self.filter).to_owned()),
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<ConstantScoreQuery<'a>> for QueryType<'a> {
+    fn from(constant_score_query: ConstantScoreQuery<'a>) -> Self {
+        QueryType::ConstantScore(constant_score_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for ConstantScoreQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut constant_score_obj = Map::new();
+        constant_score_obj.insert("filter".to_string(), self.filter.to_json());
+
+        if let Some(boost) = self.boost.and_then(finite) {
+            constant_score_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert(
+            "constant_score".to_string(),
+            Value::Object(constant_score_obj),
+        );
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{QueryType, ScoreFunctionType};
+
+/// A rough, heuristic estimate of how expensive a query is to execute.
+///
+/// The score has no unit; it's only meaningful when comparing queries against each other.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryCost {
+    /// The heuristic cost, higher is more expensive
+    pub score: u32,
+    /// Warnings about expensive constructs found in the query
+    pub warnings: Vec<String>,
+}
+
+impl QueryCost {
+    fn new(score: u32) -> Self {
+        Self {
+            score,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn warn(mut self, warning: impl Into<String>) -> Self {
+        self.warnings.push(warning.into());
+        self
+    }
+
+    fn add(&mut self, other: QueryCost) {
+        self.score = self.score.saturating_add(other.score);
+        self.warnings.extend(other.warnings);
+    }
+}
+
+/// Cost of a cheap leaf query such as term or range
+const LEAF_COST: u32 = 1;
+/// Cost of a leaf query that needs analysis and positions
+const PHRASE_COST: u32 = 2;
+/// Cost of a query that expands terms at search time
+const EXPANSION_COST: u32 = 10;
+/// Cost of running a script per document
+const SCRIPT_COST: u32 = 20;
+/// Multiplier applied to a bool query nested inside another bool query
+const NESTED_BOOL_MULTIPLIER: u32 = 2;
+
+impl<'a> QueryType<'a> {
+    /// Estimate how expensive this query is to execute.
+    ///
+    /// Leaf queries are cheap, wildcard and regexp queries are expensive, scripts are more
+    /// expensive still, and bool queries nested inside other bool queries multiply their cost.
+    pub fn estimate_cost(&self) -> QueryCost {
+        self.estimate_cost_at_depth(0)
+    }
+
+    fn estimate_cost_at_depth(&self, bool_depth: u32) -> QueryCost {
+        match self {
+            QueryType::Bool(bool_query) => {
+                let mut cost = QueryCost::new(LEAF_COST);
+                for clause in bool_query
+                    .must
+                    .iter()
+                    .chain(bool_query.must_not.iter())
+                    .chain(bool_query.should.iter())
+                    .chain(bool_query.filter.iter())
+                {
+                    cost.add(clause.estimate_cost_at_depth(bool_depth + 1));
+                }
+                if bool_depth > 0 {
+                    cost.score = cost.score.saturating_mul(NESTED_BOOL_MULTIPLIER);
+                }
+                cost
+            }
+            QueryType::ConstantScore(constant_score) => {
+                let mut cost = QueryCost::new(LEAF_COST);
+                cost.add(constant_score.filter.estimate_cost_at_depth(bool_depth));
+                cost
+            }
+            // Every query is scored to find the best match, like a bool should
+            QueryType::DisMax(dis_max) => {
+                let mut cost = QueryCost::new(LEAF_COST);
+                for query in dis_max.queries.iter() {
+                    cost.add(query.estimate_cost_at_depth(bool_depth));
+                }
+                cost
+            }
+            QueryType::Nested(nested) => {
+                let mut cost = QueryCost::new(PHRASE_COST);
+                cost.add(nested.query.estimate_cost_at_depth(bool_depth));
+                cost
+            }
+            // Joins have to look up the related documents for every match
+            QueryType::HasChild(has_child) => {
+                let mut cost = QueryCost::new(EXPANSION_COST);
+                cost.add(has_child.query.estimate_cost_at_depth(bool_depth));
+                cost
+            }
+            QueryType::HasParent(has_parent) => {
+                let mut cost = QueryCost::new(EXPANSION_COST);
+                cost.add(has_parent.query.estimate_cost_at_depth(bool_depth));
+                cost
+            }
+            QueryType::FunctionScore(function_score) => {
+                let mut cost = QueryCost::new(LEAF_COST);
+                if let Some(ref query) = function_score.query {
+                    cost.add(query.estimate_cost_at_depth(bool_depth));
+                }
+                for function in function_score.functions.iter() {
+                    if let Some(ref filter) = function.filter {
+                        cost.add(filter.estimate_cost_at_depth(bool_depth));
+                    }
+                    cost.add(match function.function {
+                        ScoreFunctionType::ScriptScore(_) => {
+                            QueryCost::new(SCRIPT_COST).warn("script score function")
+                        }
+                        _ => QueryCost::new(LEAF_COST),
+                    });
+                }
+                cost
+            }
+            QueryType::MatchPhrase(_) => QueryCost::new(PHRASE_COST),
+            // The query string may contain any syntax, including wildcards and regexps
+            QueryType::QueryString(_) => QueryCost::new(EXPANSION_COST),
+            QueryType::MatchPhrasePrefix(_) => QueryCost::new(PHRASE_COST + EXPANSION_COST / 2),
+            QueryType::MatchAll(_)
+            | QueryType::GeoDistance(_)
+            | QueryType::MatchNone
+            | QueryType::Match(_)
+            | QueryType::MultiMatch(_)
+            | QueryType::Range(_)
+            | QueryType::Term(_)
+            | QueryType::Terms(_) => QueryCost::new(LEAF_COST),
+            QueryType::Regexp(regexp) => QueryCost::new(EXPANSION_COST)
+                .warn(format!("regexp query on field \"{}\"", regexp.field)),
+            QueryType::WildCard(wildcard) => {
+                let cost = QueryCost::new(EXPANSION_COST);
+                if wildcard.has_leading_wildcard() {
+                    cost.warn(format!(
+                        "leading wildcard on field \"{}\"",
+                        wildcard.field()
+                    ))
+                } else {
+                    cost
+                }
+            }
+            QueryType::Raw(_) => QueryCost::new(LEAF_COST).warn("raw query cost is unknown"),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::util::finite;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Disjunction Max Query
+#[derive(Default, Debug, Clone, Serialize)]
+pub struct DisMaxQuery<'a> {
+    /// The queries, a document scores with the best matching one
+    #[serde(borrow)]
+    pub queries: Cow<'a, [QueryType<'a>]>,
+    /// How much the other matching queries add to the score, between 0.0 and 1.0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tie_breaker: Option<f64>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> DisMaxQuery<'a> {
+    /// Create a new empty DisMaxQuery
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a query
+    pub fn query(mut self, query: QueryType<'a>) -> Self {
+        self.queries.to_mut().push(query);
+        self
+    }
+
+    /// Set the tie breaker
+    pub fn tie_breaker(mut self, tie_breaker: f64) -> Self {
+        self.tie_breaker = Some(tie_breaker);
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> DisMaxQuery<'static> {
+        DisMaxQuery {
+            queries: Cow::Owned(self.queries.iter().map(|q| q.to_owned()).collect()),
+            tie_breaker: self.tie_breaker,
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<DisMaxQuery<'a>> for QueryType<'a> {
+    fn from(dis_max_query: DisMaxQuery<'a>) -> Self {
+        QueryType::DisMax(dis_max_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for DisMaxQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut dis_max_obj = Map::new();
+
+        let queries: Vec<Value> = self.queries.iter().map(|q| q.to_json()).collect();
+        dis_max_obj.insert("queries".to_string(), Value::Array(queries));
+
+        if let Some(tie_breaker) = self.tie_breaker.and_then(finite) {
+            dis_max_obj.insert("tie_breaker".to_string(), tie_breaker.into());
+        }
+
+        if let Some(boost) = self.boost.and_then(finite) {
+            dis_max_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("dis_max".to_string(), Value::Object(dis_max_obj));
+        Value::Object(result)
+    }
+}
+
+/// Builder pattern for DisMaxQuery that allows dynamic updates.
+#[derive(Default, Debug, Clone)]
+pub struct DisMaxQueryBuilder<'a> {
+    queries: Cow<'a, [QueryType<'a>]>,
+    tie_breaker: Option<f64>,
+    boost: Option<f64>,
+}
+
+impl<'a> DisMaxQueryBuilder<'a> {
+    /// Create a new empty DisMaxQueryBuilder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new DisMaxQueryBuilder with the tie breaker explicitly set to its default of 0.0,
+    /// so scoring by the best matching query alone is a visible choice
+    pub fn with_defaults() -> Self {
+        Self {
+            tie_breaker: Some(0.0),
+            ..Self::default()
+        }
+    }
+
+    /// Add a query (can be called multiple times)
+    pub fn query(&mut self, query: QueryType<'a>) -> &mut Self {
+        self.queries.to_mut().push(query);
+        self
+    }
+
+    /// Set the tie breaker (replaces existing tie breaker)
+    pub fn tie_breaker(&mut self, tie_breaker: f64) -> &mut Self {
+        self.tie_breaker = Some(tie_breaker);
+        self
+    }
+
+    /// Set the boost (replaces existing boost)
+    pub fn boost(&mut self, boost: f64) -> &mut Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Build the final DisMaxQuery
+    pub fn build(self) -> DisMaxQuery<'a> {
+        DisMaxQuery {
+            queries: self.queries,
+            tie_breaker: self.tie_breaker,
+            boost: self.boost,
+        }
+    }
+}
@@ -19,7 +19,7 @@ pub use score_mode::*;
 pub use script_score::*;
 use serde_json::{Map, Value};
 
-use crate::util::is_empty_slice;
+use crate::util::{finite, is_empty_slice};
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Function Score Query
@@ -125,9 +125,15 @@ impl<'a> ToOpenSearchJson for FunctionScoreQuery<'a> {
             function_score_obj.insert("query".to_string(), query.to_json());
         }
 
-        // Add functions array if not empty
-        if !self.functions.is_empty() {
-            let functions: Vec<Value> = self.functions.iter().map(|f| f.to_json()).collect();
+        // Add functions array if not empty, dropping weight-only functions whose weight was
+        // skipped as non-finite since OpenSearch rejects an entry without a function
+        let functions: Vec<Value> = self
+            .functions
+            .iter()
+            .filter(|f| !f.is_missing_weight())
+            .map(|f| f.to_json())
+            .collect();
+        if !functions.is_empty() {
             function_score_obj.insert("functions".to_string(), Value::Array(functions));
         }
 
@@ -135,14 +141,7 @@ impl<'a> ToOpenSearchJson for FunctionScoreQuery<'a> {
         if let Some(ref score_mode) = self.score_mode {
             function_score_obj.insert(
                 "score_mode".to_string(),
-                Value::String(match score_mode {
-                    ScoreMode::Multiply => "multiply".to_string(),
-                    ScoreMode::Sum => "sum".to_string(),
-                    ScoreMode::Avg => "avg".to_string(),
-                    ScoreMode::First => "first".to_string(),
-                    ScoreMode::Max => "max".to_string(),
-                    ScoreMode::Min => "min".to_string(),
-                }),
+                Value::String(score_mode.as_str().to_string()),
             );
         }
 
@@ -162,17 +161,17 @@ impl<'a> ToOpenSearchJson for FunctionScoreQuery<'a> {
         }
 
         // Add max_boost if present
-        if let Some(max_boost) = self.max_boost {
+        if let Some(max_boost) = self.max_boost.and_then(finite) {
             function_score_obj.insert("max_boost".to_string(), max_boost.into());
         }
 
         // Add boost if present
-        if let Some(boost) = self.boost {
+        if let Some(boost) = self.boost.and_then(finite) {
             function_score_obj.insert("boost".to_string(), boost.into());
         }
 
         // Add min_score if present
-        if let Some(min_score) = self.min_score {
+        if let Some(min_score) = self.min_score.and_then(finite) {
             function_score_obj.insert("min_score".to_string(), min_score.into());
         }
 
@@ -210,6 +209,16 @@ impl<'a> FunctionScoreQueryBuilder<'a> {
         Self::default()
     }
 
+    /// Create a new FunctionScoreQueryBuilder with the score mode and boost mode explicitly set
+    /// to their defaults, so the generated query doesn't rely on OpenSearch's implicit behavior
+    pub fn with_defaults() -> Self {
+        Self {
+            score_mode: Some(ScoreMode::default()),
+            boost_mode: Some(BoostMode::default()),
+            ..Self::default()
+        }
+    }
+
     /// Set the query for this query (replaces existing query)
     pub fn query(&mut self, query: QueryType<'a>) -> &mut Self {
         self.query = Some(Box::new(query));
@@ -265,3 +274,6 @@ impl<'a> FunctionScoreQueryBuilder<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test;
@@ -1,10 +1,13 @@
 use serde::Serialize;
 
 /// Boost mode
-#[derive(Debug, Clone, Serialize)]
+///
+/// Defaults to [`BoostMode::Multiply`], matching OpenSearch when no boost mode is set.
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BoostMode {
     /// Multiply the boost by the function's result
+    #[default]
     Multiply,
     /// Replace the boost with the function's result
     Replace,
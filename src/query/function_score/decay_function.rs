@@ -3,6 +3,8 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::{ValidationError, require_finite};
+
 /// Decay function configuration
 #[derive(Debug, Clone, Serialize)]
 pub struct DecayFunction<'a> {
@@ -53,6 +55,11 @@ impl<'a> DecayFunction<'a> {
         self
     }
 
+    /// Set the decay, rejecting a NaN or infinite value
+    pub fn try_decay(self, decay: f64) -> Result<Self, ValidationError> {
+        Ok(self.decay(require_finite("decay", decay)?))
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> DecayFunction<'static> {
         DecayFunction {
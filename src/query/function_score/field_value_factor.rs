@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
+use crate::{ValidationError, require_finite};
+
 /// Field value factor configuration
 #[derive(Debug, Clone, Serialize)]
 pub struct FieldValueFactor<'a> {
@@ -36,6 +38,11 @@ impl<'a> FieldValueFactor<'a> {
         self
     }
 
+    /// Set the factor, rejecting a NaN or infinite value
+    pub fn try_factor(self, factor: f64) -> Result<Self, ValidationError> {
+        Ok(self.factor(require_finite("factor", factor)?))
+    }
+
     /// Set the modifier
     pub fn modifier(mut self, modifier: impl Into<Cow<'a, str>>) -> Self {
         self.modifier = Some(modifier.into());
@@ -4,6 +4,10 @@ use serde::Serialize;
 use serde_json::Value;
 
 /// Random score configuration
+///
+/// Without a seed the scores change on every request. A seed alone is only reproducible while
+/// the shards stay put, so pair it with a field such as `_seq_no` for scores that are stable
+/// across shard movement; see [`RandomScore::reproducible`].
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct RandomScore<'a> {
     /// The seed to use for randomizing
@@ -20,6 +24,11 @@ impl<'a> RandomScore<'a> {
         Self::default()
     }
 
+    /// Create a RandomScore that is reproducible across requests and shard movement
+    pub fn reproducible<T: Into<Value>>(seed: T, field: impl Into<Cow<'a, str>>) -> Self {
+        Self::new().seed(seed).field(field)
+    }
+
     /// Set the seed
     pub fn seed<T: Into<Value>>(mut self, seed: T) -> Self {
         self.seed = Some(seed.into());
@@ -1,8 +1,10 @@
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{
     DecayFunction, FieldValueFactor, QueryType, RandomScore, ScriptScore, ToOpenSearchJson,
+    ValidationError, require_finite,
 };
 
 /// Enum representing different scoring functions
@@ -26,6 +28,11 @@ pub enum ScoreFunctionType<'a> {
 }
 
 impl<'a> ScoreFunctionType<'a> {
+    /// Create a weight function, rejecting a NaN or infinite weight
+    pub fn try_weight(weight: f64) -> Result<Self, ValidationError> {
+        Ok(ScoreFunctionType::Weight(require_finite("weight", weight)?))
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> ScoreFunctionType<'static> {
         match self {
@@ -65,6 +72,13 @@ impl<'a> ScoreFunction<'a> {
             weight: self.weight,
         }
     }
+
+    /// Whether this is a weight-only function with no finite weight, which has nothing left to
+    /// emit once the non-finite weight is skipped
+    pub(crate) fn is_missing_weight(&self) -> bool {
+        matches!(self.function, ScoreFunctionType::Weight(weight) if !weight.is_finite())
+            && !self.weight.is_some_and(f64::is_finite)
+    }
 }
 
 impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
@@ -84,7 +98,7 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
                 if let Some(ref offset) = decay.offset {
                     field_obj.insert("offset".to_string(), Value::String(offset.to_string()));
                 }
-                if let Some(decay_val) = decay.decay {
+                if let Some(decay_val) = decay.decay.and_then(finite) {
                     field_obj.insert("decay".to_string(), decay_val.into());
                 }
 
@@ -102,7 +116,7 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
                 if let Some(ref offset) = decay.offset {
                     field_obj.insert("offset".to_string(), Value::String(offset.to_string()));
                 }
-                if let Some(decay_val) = decay.decay {
+                if let Some(decay_val) = decay.decay.and_then(finite) {
                     field_obj.insert("decay".to_string(), decay_val.into());
                 }
 
@@ -120,7 +134,7 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
                 if let Some(ref offset) = decay.offset {
                     field_obj.insert("offset".to_string(), Value::String(offset.to_string()));
                 }
-                if let Some(decay_val) = decay.decay {
+                if let Some(decay_val) = decay.decay.and_then(finite) {
                     field_obj.insert("decay".to_string(), decay_val.into());
                 }
 
@@ -130,7 +144,7 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
             ScoreFunctionType::FieldValueFactor(fvf) => {
                 let mut fvf_obj = Map::new();
                 fvf_obj.insert("field".to_string(), Value::String(fvf.field.to_string()));
-                if let Some(factor) = fvf.factor {
+                if let Some(factor) = fvf.factor.and_then(finite) {
                     fvf_obj.insert("factor".to_string(), factor.into());
                 }
                 if let Some(ref modifier) = fvf.modifier {
@@ -152,17 +166,15 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
                 result.insert("random_score".to_string(), Value::Object(rs_obj));
             }
             ScoreFunctionType::ScriptScore(ss) => {
-                let mut script_obj = Map::new();
-                script_obj.insert("source".to_string(), Value::String(ss.source.to_string()));
-                if let Some(ref params) = ss.params {
-                    script_obj.insert("params".to_string(), Value::Object(params.clone()));
-                }
                 let mut ss_obj = Map::new();
-                ss_obj.insert("script".to_string(), Value::Object(script_obj));
+                ss_obj.insert("script".to_string(), ss.script.to_json());
                 result.insert("script_score".to_string(), Value::Object(ss_obj));
             }
-            ScoreFunctionType::Weight(_) => {
-                // Weight-only functions don't add a function type field
+            ScoreFunctionType::Weight(weight) => {
+                // Weight-only functions have no function type field, just the weight itself
+                if let Some(weight) = finite(*weight) {
+                    result.insert("weight".to_string(), weight.into());
+                }
             }
         }
 
@@ -171,8 +183,8 @@ impl<'a> ToOpenSearchJson for ScoreFunction<'a> {
             result.insert("filter".to_string(), filter.to_json());
         }
 
-        // Add weight if present
-        if let Some(weight) = self.weight {
+        // Add weight if present, overriding the weight of a weight-only function
+        if let Some(weight) = self.weight.and_then(finite) {
             result.insert("weight".to_string(), weight.into());
         }
 
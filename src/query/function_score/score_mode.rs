@@ -1,19 +1,36 @@
 use serde::Serialize;
 
-/// Score mode
-#[derive(Debug, Clone, Serialize)]
+/// Score mode, controlling how the scores of the individual functions are combined
+///
+/// Defaults to [`ScoreMode::Multiply`], matching OpenSearch when no score mode is set.
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ScoreMode {
-    /// Multiply the score by the function's result
+    /// Multiply the function scores together
+    #[default]
     Multiply,
-    /// Add the function's result to the score
+    /// Add the function scores together
     Sum,
-    /// Average the function's result with the score
+    /// Average the function scores
     Avg,
-    /// Take the minimum of the function's result and the score
+    /// Use the score of the first function whose filter matches the document
     First,
-    /// Take the maximum of the function's result and the score
+    /// Take the maximum of the function scores
     Max,
-    /// Take the minimum of the function's result and the score
+    /// Take the minimum of the function scores
     Min,
 }
+
+impl ScoreMode {
+    /// The score mode as sent to OpenSearch
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreMode::Multiply => "multiply",
+            ScoreMode::Sum => "sum",
+            ScoreMode::Avg => "avg",
+            ScoreMode::First => "first",
+            ScoreMode::Max => "max",
+            ScoreMode::Min => "min",
+        }
+    }
+}
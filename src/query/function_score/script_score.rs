@@ -3,37 +3,46 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::{Lang, Script};
+
 /// Script score configuration
 #[derive(Debug, Clone, Serialize)]
 pub struct ScriptScore<'a> {
     /// The script to use for scoring
     #[serde(borrow)]
-    pub source: Cow<'a, str>,
-    /// The parameters to use for scoring
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub params: Option<Map<String, Value>>,
+    pub script: Script<'a>,
 }
 
 impl<'a> ScriptScore<'a> {
     /// Create a new ScriptScore
     pub fn new(source: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            source: source.into(),
-            params: None,
+            script: Script::new(source),
         }
     }
 
+    /// Set the script language
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.script = self.script.lang(lang);
+        self
+    }
+
     /// Set the parameters
     pub fn params(mut self, params: Map<String, Value>) -> Self {
-        self.params = Some(params);
+        self.script = self.script.params(Value::Object(params));
         self
     }
 
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> ScriptScore<'static> {
         ScriptScore {
-            source: Cow::Owned(self.source.to_string()),
-            params: self.params.clone(),
+            script: self.script.to_owned(),
         }
     }
 }
+
+impl<'a> From<Script<'a>> for ScriptScore<'a> {
+    fn from(script: Script<'a>) -> Self {
+        Self { script }
+    }
+}
@@ -0,0 +1,342 @@
+use super::*;
+use crate::{Lang, Script};
+
+#[test]
+fn test_function_score_with_defaults() {
+    let mut builder = FunctionScoreQueryBuilder::with_defaults();
+    builder.query(QueryType::term("status", "published"));
+
+    assert_eq!(
+        builder.build().to_json(),
+        serde_json::json!({
+            "function_score": {
+                "query": {"term": {"status": "published"}},
+                "score_mode": "multiply",
+                "boost_mode": "multiply"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_new_has_no_modes() {
+    let result = FunctionScoreQueryBuilder::new().build().to_json();
+
+    assert_eq!(result, serde_json::json!({"function_score": {}}));
+}
+
+#[test]
+fn test_score_mode_serialization() {
+    let cases = [
+        (ScoreMode::Multiply, "multiply"),
+        (ScoreMode::Sum, "sum"),
+        (ScoreMode::Avg, "avg"),
+        (ScoreMode::First, "first"),
+        (ScoreMode::Max, "max"),
+        (ScoreMode::Min, "min"),
+    ];
+
+    for (score_mode, expected) in cases {
+        assert_eq!(score_mode.as_str(), expected);
+        assert_eq!(serde_json::to_value(&score_mode).unwrap(), expected);
+        assert_eq!(
+            FunctionScoreQuery::new().score_mode(score_mode).to_json(),
+            serde_json::json!({"function_score": {"score_mode": expected}})
+        );
+    }
+}
+
+fn function(function: ScoreFunctionType<'static>) -> ScoreFunction<'static> {
+    ScoreFunction {
+        function,
+        filter: None,
+        weight: None,
+    }
+}
+
+#[test]
+fn test_function_score_gauss() {
+    let result = function(ScoreFunctionType::Gauss(
+        DecayFunction::new("location", "2km")
+            .origin("11, 12")
+            .offset("0km")
+            .decay(0.33),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "gauss": {
+                "location": {
+                    "origin": "11, 12",
+                    "scale": "2km",
+                    "offset": "0km",
+                    "decay": 0.33
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_exp() {
+    let result = function(ScoreFunctionType::Exp(
+        DecayFunction::new("published_at", "10d").origin("now"),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "exp": {
+                "published_at": {
+                    "origin": "now",
+                    "scale": "10d"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_linear() {
+    let result = function(ScoreFunctionType::Linear(
+        DecayFunction::new("price", "20").origin(100),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "linear": {
+                "price": {
+                    "origin": 100,
+                    "scale": "20"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_field_value_factor() {
+    let result = function(ScoreFunctionType::FieldValueFactor(
+        FieldValueFactor::new("likes")
+            .factor(1.2)
+            .modifier("sqrt")
+            .missing(1.0),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "field_value_factor": {
+                "field": "likes",
+                "factor": 1.2,
+                "modifier": "sqrt",
+                "missing": 1.0
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_random_score() {
+    let result = function(ScoreFunctionType::RandomScore(
+        RandomScore::new().seed(10).field("_seq_no"),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "random_score": {
+                "seed": 10,
+                "field": "_seq_no"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_script_score() {
+    let mut params = serde_json::Map::new();
+    params.insert("factor".to_string(), serde_json::json!(2));
+
+    let result = function(ScoreFunctionType::ScriptScore(
+        ScriptScore::new("_score * params.factor").params(params),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "script_score": {
+                "script": {
+                    "source": "_score * params.factor",
+                    "lang": "painless",
+                    "params": {"factor": 2}
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_function_score_weight() {
+    let result = function(ScoreFunctionType::Weight(3.0)).to_json();
+
+    assert_eq!(result, serde_json::json!({"weight": 3.0}));
+}
+
+#[test]
+fn test_function_score_weight_with_filter() {
+    let result = ScoreFunction {
+        function: ScoreFunctionType::Weight(3.0),
+        filter: Some(Box::new(QueryType::term("featured", true))),
+        weight: None,
+    }
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "filter": {"term": {"featured": true}},
+            "weight": 3.0
+        })
+    );
+}
+
+#[test]
+fn test_function_score_filter_and_weight() {
+    let result = ScoreFunction {
+        function: ScoreFunctionType::FieldValueFactor(FieldValueFactor::new("likes")),
+        filter: Some(Box::new(QueryType::term("featured", true))),
+        weight: Some(2.0),
+    }
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "field_value_factor": {"field": "likes"},
+            "filter": {"term": {"featured": true}},
+            "weight": 2.0
+        })
+    );
+}
+
+#[test]
+fn test_function_score_script_score_lang() {
+    let result = function(ScoreFunctionType::ScriptScore(
+        ScriptScore::new("doc['likes'].value * 2").lang(Lang::Expression),
+    ))
+    .to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "script_score": {
+                "script": {
+                    "source": "doc['likes'].value * 2",
+                    "lang": "expression"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_script_score_from_shared_script() {
+    let script = Script::new("_score * params.factor").params(serde_json::json!({"factor": 2}));
+    let script_score = ScriptScore::from(script.clone());
+
+    assert_eq!(script_score.script.to_json(), script.to_json());
+}
+
+#[test]
+fn test_non_finite_floats_are_skipped() {
+    let mut builder = FunctionScoreQueryBuilder::new();
+    builder
+        .query(QueryType::match_all())
+        .function(function(ScoreFunctionType::Weight(f64::NAN)))
+        .max_boost(f64::INFINITY)
+        .boost(f64::NEG_INFINITY)
+        .min_score(0.5);
+
+    assert_eq!(
+        builder.build().to_json(),
+        serde_json::json!({
+            "function_score": {
+                "query": {"match_all": {}},
+                "min_score": 0.5
+            }
+        })
+    );
+    assert_eq!(
+        QueryType::term("status", "active")
+            .with_boost(f64::NAN)
+            .to_json(),
+        serde_json::json!({"term": {"status": "active"}})
+    );
+    assert_eq!(
+        QueryType::terms("status", ["active"])
+            .with_boost(f64::NAN)
+            .to_json(),
+        serde_json::json!({"terms": {"status": ["active"]}})
+    );
+    assert_eq!(
+        crate::MatchQuery::new("title", "fox")
+            .boost(f64::NAN)
+            .to_json(),
+        serde_json::json!({"match": {"title": "fox"}})
+    );
+}
+
+#[test]
+fn test_try_setters_reject_non_finite() {
+    assert!(matches!(
+        QueryType::match_all().try_with_boost(f64::NAN),
+        Err(crate::ValidationError::NonFiniteFloat { name: "boost", .. })
+    ));
+    assert_eq!(
+        QueryType::match_all().try_with_boost(2.0).unwrap().boost(),
+        Some(2.0)
+    );
+    assert_eq!(
+        QueryType::regexp("name", "jo.*")
+            .try_with_boost(2.0)
+            .unwrap_err(),
+        crate::ValidationError::BoostUnsupported { query: "regexp" }
+    );
+    assert!(
+        DecayFunction::new("date", "10d")
+            .try_decay(f64::NAN)
+            .is_err()
+    );
+    assert!(
+        FieldValueFactor::new("likes")
+            .try_factor(f64::INFINITY)
+            .is_err()
+    );
+    assert!(ScoreFunctionType::try_weight(f64::NEG_INFINITY).is_err());
+    assert!(ScoreFunctionType::try_weight(1.5).is_ok());
+}
+
+#[test]
+fn test_random_score_reproducible() {
+    assert_eq!(
+        function(ScoreFunctionType::RandomScore(RandomScore::reproducible(
+            10, "_seq_no"
+        )))
+        .to_json(),
+        serde_json::json!({
+            "random_score": {
+                "seed": 10,
+                "field": "_seq_no"
+            }
+        })
+    );
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{GeoPoint, QueryType, ToOpenSearchJson};
+
+/// Geo Distance Query
+#[derive(Debug, Clone, Serialize)]
+pub struct GeoDistanceQuery<'a> {
+    /// The geo point field to search
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The point to measure the distance from
+    pub point: GeoPoint,
+    /// The maximum distance from the point, e.g. `12km`
+    #[serde(borrow)]
+    pub distance: Cow<'a, str>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> GeoDistanceQuery<'a> {
+    /// Create a new GeoDistanceQuery, the distance is either a [`crate::Distance`] or a string
+    pub fn new(
+        field: impl Into<Cow<'a, str>>,
+        point: GeoPoint,
+        distance: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            point,
+            distance: distance.into(),
+            boost: None,
+        }
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> GeoDistanceQuery<'static> {
+        GeoDistanceQuery {
+            field: Cow::Owned(self.field.to_string()),
+            point: self.point,
+            distance: Cow::Owned(self.distance.to_string()),
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<GeoDistanceQuery<'a>> for QueryType<'a> {
+    fn from(geo_distance_query: GeoDistanceQuery<'a>) -> Self {
+        QueryType::GeoDistance(geo_distance_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for GeoDistanceQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut geo_distance_obj = Map::new();
+        geo_distance_obj.insert(
+            "distance".to_string(),
+            Value::String(self.distance.to_string()),
+        );
+        geo_distance_obj.insert(self.field.to_string(), self.point.to_json());
+
+        if let Some(boost) = self.boost.and_then(finite) {
+            geo_distance_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("geo_distance".to_string(), Value::Object(geo_distance_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Has Child Query
+#[derive(Debug, Clone, Serialize)]
+pub struct HasChildQuery<'a> {
+    /// The child relationship name of the join field
+    #[serde(borrow)]
+    pub child_type: Cow<'a, str>,
+    /// The query to run against the child documents
+    #[serde(borrow)]
+    pub query: Box<QueryType<'a>>,
+    /// How the scores of matching child documents are combined (avg, max, min, sum, none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub score_mode: Option<Cow<'a, str>>,
+    /// Whether to ignore an unmapped child type instead of failing the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_unmapped: Option<bool>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> HasChildQuery<'a> {
+    /// Create a new HasChildQuery with a given child type and query
+    pub fn new(child_type: impl Into<Cow<'a, str>>, query: QueryType<'a>) -> Self {
+        Self {
+            child_type: child_type.into(),
+            query: Box::new(query),
+            score_mode: None,
+            ignore_unmapped: None,
+            boost: None,
+        }
+    }
+
+    /// Set the score mode
+    pub fn score_mode(mut self, score_mode: impl Into<Cow<'a, str>>) -> Self {
+        self.score_mode = Some(score_mode.into());
+        self
+    }
+
+    /// Set whether to ignore an unmapped child type instead of failing the query
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> HasChildQuery<'static> {
+        HasChildQuery {
+            child_type: Cow::Owned(self.child_type.to_string()),
+            query: Box::new((*self.query).to_owned()),
+            score_mode: self.score_mode.as_ref().map(|s| Cow::Owned(s.to_string())),
+            ignore_unmapped: self.ignore_unmapped,
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<HasChildQuery<'a>> for QueryType<'a> {
+    fn from(has_child_query: HasChildQuery<'a>) -> Self {
+        QueryType::HasChild(has_child_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for HasChildQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut has_child_obj = Map::new();
+        has_child_obj.insert(
+            "type".to_string(),
+            Value::String(self.child_type.to_string()),
+        );
+        has_child_obj.insert("query".to_string(), self.query.to_json());
+
+        if let Some(score_mode) = self.score_mode.as_ref() {
+            has_child_obj.insert(
+                "score_mode".to_string(),
+                Value::String(score_mode.to_string()),
+            );
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped {
+            has_child_obj.insert("ignore_unmapped".to_string(), Value::Bool(ignore_unmapped));
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
+            has_child_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("has_child".to_string(), Value::Object(has_child_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Has Parent Query
+#[derive(Debug, Clone, Serialize)]
+pub struct HasParentQuery<'a> {
+    /// The parent relationship name of the join field
+    #[serde(borrow)]
+    pub parent_type: Cow<'a, str>,
+    /// The query to run against the parent documents
+    #[serde(borrow)]
+    pub query: Box<QueryType<'a>>,
+    /// Whether the score of the matching parent is used for the child documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<bool>,
+    /// Whether to ignore an unmapped parent type instead of failing the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_unmapped: Option<bool>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> HasParentQuery<'a> {
+    /// Create a new HasParentQuery with a given parent type and query
+    pub fn new(parent_type: impl Into<Cow<'a, str>>, query: QueryType<'a>) -> Self {
+        Self {
+            parent_type: parent_type.into(),
+            query: Box::new(query),
+            score: None,
+            ignore_unmapped: None,
+            boost: None,
+        }
+    }
+
+    /// Set whether the score of the matching parent is used for the child documents
+    pub fn score(mut self, score: bool) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Set whether to ignore an unmapped parent type instead of failing the query
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> HasParentQuery<'static> {
+        HasParentQuery {
+            parent_type: Cow::Owned(self.parent_type.to_string()),
+            query: Box::new((*self.query).to_owned()),
+            score: self.score,
+            ignore_unmapped: self.ignore_unmapped,
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<HasParentQuery<'a>> for QueryType<'a> {
+    fn from(has_parent_query: HasParentQuery<'a>) -> Self {
+        QueryType::HasParent(has_parent_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for HasParentQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut has_parent_obj = Map::new();
+        has_parent_obj.insert(
+            "parent_type".to_string(),
+            Value::String(self.parent_type.to_string()),
+        );
+        has_parent_obj.insert("query".to_string(), self.query.to_json());
+
+        if let Some(score) = self.score {
+            has_parent_obj.insert("score".to_string(), Value::Bool(score));
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped {
+            has_parent_obj.insert("ignore_unmapped".to_string(), Value::Bool(ignore_unmapped));
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
+            has_parent_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("has_parent".to_string(), Value::Object(has_parent_obj));
+        Value::Object(result)
+    }
+}
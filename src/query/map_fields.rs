@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use crate::{QueryType, ScoreFunctionType};
+
+impl<'a> QueryType<'a> {
+    /// Return a copy of the query with every field name passed through `f`, e.g. to prefix all
+    /// fields with a tenant name.
+    ///
+    /// Nested queries are rewritten recursively, including nested paths, score function fields
+    /// and query string fields. Raw queries are opaque and left unchanged.
+    pub fn map_fields<F: Fn(&str) -> String>(&self, f: &F) -> QueryType<'static> {
+        let mut query = self.to_owned();
+        query.map_fields_in_place(f);
+        query
+    }
+
+    pub(crate) fn map_fields_in_place<F: Fn(&str) -> String>(&mut self, f: &F) {
+        let map = |field: &mut Cow<'a, str>| *field = Cow::Owned(f(field));
+
+        match self {
+            QueryType::Bool(bool_query) => {
+                for clause in bool_query
+                    .must
+                    .to_mut()
+                    .iter_mut()
+                    .chain(bool_query.must_not.to_mut().iter_mut())
+                    .chain(bool_query.should.to_mut().iter_mut())
+                    .chain(bool_query.filter.to_mut().iter_mut())
+                {
+                    clause.map_fields_in_place(f);
+                }
+            }
+            QueryType::ConstantScore(constant_score) => {
+                constant_score.filter.map_fields_in_place(f)
+            }
+            QueryType::DisMax(dis_max) => {
+                for query in dis_max.queries.to_mut().iter_mut() {
+                    query.map_fields_in_place(f);
+                }
+            }
+            QueryType::FunctionScore(function_score) => {
+                if let Some(ref mut query) = function_score.query {
+                    query.map_fields_in_place(f);
+                }
+                for function in function_score.functions.to_mut().iter_mut() {
+                    if let Some(ref mut filter) = function.filter {
+                        filter.map_fields_in_place(f);
+                    }
+                    match function.function {
+                        ScoreFunctionType::Gauss(ref mut decay)
+                        | ScoreFunctionType::Exp(ref mut decay)
+                        | ScoreFunctionType::Linear(ref mut decay) => map(&mut decay.field),
+                        ScoreFunctionType::FieldValueFactor(ref mut fvf) => map(&mut fvf.field),
+                        ScoreFunctionType::RandomScore(ref mut rs) => {
+                            if let Some(ref mut field) = rs.field {
+                                map(field);
+                            }
+                        }
+                        ScoreFunctionType::ScriptScore(_) | ScoreFunctionType::Weight(_) => {}
+                    }
+                }
+            }
+            QueryType::GeoDistance(geo_distance) => map(&mut geo_distance.field),
+            QueryType::HasChild(has_child) => has_child.query.map_fields_in_place(f),
+            QueryType::HasParent(has_parent) => has_parent.query.map_fields_in_place(f),
+            QueryType::MatchPhrase(match_phrase) => map(&mut match_phrase.field),
+            QueryType::MatchPhrasePrefix(match_phrase_prefix) => {
+                map(&mut match_phrase_prefix.field)
+            }
+            QueryType::Match(match_query) => map(&mut match_query.field),
+            QueryType::MultiMatch(multi_match) => multi_match.fields.iter_mut().for_each(map),
+            QueryType::Nested(nested) => {
+                map(&mut nested.path);
+                nested.query.map_fields_in_place(f);
+            }
+            QueryType::QueryString(query_string) => {
+                if let Some(ref mut default_field) = query_string.default_field {
+                    map(default_field);
+                }
+                query_string.fields.iter_mut().for_each(map);
+            }
+            QueryType::Range(range) => map(&mut range.field),
+            QueryType::Regexp(regexp) => map(&mut regexp.field),
+            QueryType::Term(term) => map(&mut term.field),
+            QueryType::Terms(terms) => map(&mut terms.field),
+            QueryType::WildCard(wildcard) => map(wildcard.field_mut()),
+            QueryType::MatchAll(_) | QueryType::MatchNone | QueryType::Raw(_) => {}
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Match All Query
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MatchAllQuery {
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl MatchAllQuery {
+    /// Create a new MatchAllQuery
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+}
+
+impl<'a> From<MatchAllQuery> for QueryType<'a> {
+    fn from(match_all_query: MatchAllQuery) -> Self {
+        QueryType::MatchAll(match_all_query)
+    }
+}
+
+impl ToOpenSearchJson for MatchAllQuery {
+    fn to_json(&self) -> Value {
+        let mut match_all_obj = Map::new();
+
+        if let Some(boost) = self.boost.and_then(finite) {
+            match_all_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("match_all".to_string(), Value::Object(match_all_obj));
+        Value::Object(result)
+    }
+}
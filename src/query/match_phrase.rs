@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Match Phrase Query
@@ -80,7 +81,8 @@ impl<'a> ToOpenSearchJson for MatchPhraseQuery<'a> {
         let mut match_phrase_obj = Map::new();
 
         // Check if we need the complex form
-        let has_options = self.slop.is_some() || self.analyzer.is_some() || self.boost.is_some();
+        let has_options =
+            self.slop.is_some() || self.analyzer.is_some() || self.boost.and_then(finite).is_some();
 
         if has_options {
             // Complex form with options
@@ -93,7 +95,7 @@ impl<'a> ToOpenSearchJson for MatchPhraseQuery<'a> {
             if let Some(slop) = self.slop {
                 field_obj.insert("slop".to_string(), Value::Number(slop.into()));
             }
-            if let Some(boost) = self.boost {
+            if let Some(boost) = self.boost.and_then(finite) {
                 field_obj.insert("boost".to_string(), boost.into());
             }
 
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Match Phrase Prefix Query
@@ -91,7 +92,7 @@ impl<'a> ToOpenSearchJson for MatchPhrasePrefixQuery<'a> {
         if let Some(slop) = self.slop {
             field_obj.insert("slop".to_string(), Value::Number(slop.into()));
         }
-        if let Some(boost) = self.boost {
+        if let Some(boost) = self.boost.and_then(finite) {
             field_obj.insert("boost".to_string(), boost.into());
         }
 
@@ -3,7 +3,8 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
-use crate::{QueryType, ToOpenSearchJson};
+use crate::util::finite;
+use crate::{MinimumShouldMatch, QueryType, ToOpenSearchJson};
 
 /// Match Query
 #[derive(Debug, Clone, Serialize)]
@@ -22,13 +23,32 @@ pub struct MatchQuery<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(borrow)]
     pub fuzziness: Option<Cow<'a, str>>,
+    /// The analyzer to use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub analyzer: Option<Cow<'a, str>>,
     /// The boost value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
     /// The minimum should match value
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_should_match: Option<MinimumShouldMatch>,
+    /// Whether to ignore format errors, e.g. text in a numeric field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lenient: Option<bool>,
+    /// The rewrite method for the fuzzy terms
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(borrow)]
-    pub minimum_should_match: Option<Cow<'a, str>>,
+    pub fuzzy_rewrite: Option<Cow<'a, str>>,
+    /// Whether fuzzy matching counts swapping two adjacent characters as one edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy_transpositions: Option<bool>,
+    /// The number of leading characters left unchanged by fuzzy matching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_length: Option<u32>,
+    /// The maximum number of terms fuzzy matching expands to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_expansions: Option<u32>,
 }
 
 impl<'a> MatchQuery<'a> {
@@ -39,8 +59,14 @@ impl<'a> MatchQuery<'a> {
             query: query.into(),
             operator: None,
             fuzziness: None,
+            analyzer: None,
             boost: None,
             minimum_should_match: None,
+            lenient: None,
+            fuzzy_rewrite: None,
+            fuzzy_transpositions: None,
+            prefix_length: None,
+            max_expansions: None,
         }
     }
 
@@ -56,6 +82,12 @@ impl<'a> MatchQuery<'a> {
         self
     }
 
+    /// Set the analyzer to use
+    pub fn analyzer(mut self, analyzer: impl Into<Cow<'a, str>>) -> Self {
+        self.analyzer = Some(analyzer.into());
+        self
+    }
+
     /// Set the boost value
     pub fn boost(mut self, boost: f64) -> Self {
         self.boost = Some(boost);
@@ -63,11 +95,44 @@ impl<'a> MatchQuery<'a> {
     }
 
     /// Set the minimum should match value
-    pub fn minimum_should_match(mut self, minimum_should_match: impl Into<Cow<'a, str>>) -> Self {
+    pub fn minimum_should_match(
+        mut self,
+        minimum_should_match: impl Into<MinimumShouldMatch>,
+    ) -> Self {
         self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
+    /// Set whether to ignore format errors
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = Some(lenient);
+        self
+    }
+
+    /// Set the rewrite method for the fuzzy terms
+    pub fn fuzzy_rewrite(mut self, fuzzy_rewrite: impl Into<Cow<'a, str>>) -> Self {
+        self.fuzzy_rewrite = Some(fuzzy_rewrite.into());
+        self
+    }
+
+    /// Set whether fuzzy matching counts swapping two adjacent characters as one edit
+    pub fn fuzzy_transpositions(mut self, fuzzy_transpositions: bool) -> Self {
+        self.fuzzy_transpositions = Some(fuzzy_transpositions);
+        self
+    }
+
+    /// Set the number of leading characters left unchanged by fuzzy matching
+    pub fn prefix_length(mut self, prefix_length: u32) -> Self {
+        self.prefix_length = Some(prefix_length);
+        self
+    }
+
+    /// Set the maximum number of terms fuzzy matching expands to
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> MatchQuery<'static> {
         MatchQuery {
@@ -75,11 +140,17 @@ impl<'a> MatchQuery<'a> {
             query: Cow::Owned(self.query.to_string()),
             operator: self.operator.as_ref().map(|o| Cow::Owned(o.to_string())),
             fuzziness: self.fuzziness.as_ref().map(|f| Cow::Owned(f.to_string())),
+            analyzer: self.analyzer.as_ref().map(|a| Cow::Owned(a.to_string())),
             boost: self.boost,
-            minimum_should_match: self
-                .minimum_should_match
+            minimum_should_match: self.minimum_should_match.clone(),
+            lenient: self.lenient,
+            fuzzy_rewrite: self
+                .fuzzy_rewrite
                 .as_ref()
-                .map(|m| Cow::Owned(m.to_string())),
+                .map(|r| Cow::Owned(r.to_string())),
+            fuzzy_transpositions: self.fuzzy_transpositions,
+            prefix_length: self.prefix_length,
+            max_expansions: self.max_expansions,
         }
     }
 }
@@ -98,8 +169,14 @@ impl<'a> ToOpenSearchJson for MatchQuery<'a> {
         // Check if we need the complex form
         let has_options = self.operator.is_some()
             || self.fuzziness.is_some()
-            || self.boost.is_some()
-            || self.minimum_should_match.is_some();
+            || self.analyzer.is_some()
+            || self.boost.and_then(finite).is_some()
+            || self.minimum_should_match.is_some()
+            || self.lenient.is_some()
+            || self.fuzzy_rewrite.is_some()
+            || self.fuzzy_transpositions.is_some()
+            || self.prefix_length.is_some()
+            || self.max_expansions.is_some();
 
         if has_options {
             let mut field_obj = Map::new();
@@ -114,14 +191,44 @@ impl<'a> ToOpenSearchJson for MatchQuery<'a> {
                     Value::String(fuzziness.to_string()),
                 );
             }
-            if let Some(boost) = self.boost {
+            if let Some(ref analyzer) = self.analyzer {
+                field_obj.insert("analyzer".to_string(), Value::String(analyzer.to_string()));
+            }
+            if let Some(boost) = self.boost.and_then(finite) {
                 field_obj.insert("boost".to_string(), boost.into());
             }
 
             if let Some(ref minimum_should_match) = self.minimum_should_match {
                 field_obj.insert(
                     "minimum_should_match".to_string(),
-                    Value::String(minimum_should_match.to_string()),
+                    minimum_should_match.into(),
+                );
+            }
+            if let Some(lenient) = self.lenient {
+                field_obj.insert("lenient".to_string(), Value::Bool(lenient));
+            }
+            if let Some(ref fuzzy_rewrite) = self.fuzzy_rewrite {
+                field_obj.insert(
+                    "fuzzy_rewrite".to_string(),
+                    Value::String(fuzzy_rewrite.to_string()),
+                );
+            }
+            if let Some(fuzzy_transpositions) = self.fuzzy_transpositions {
+                field_obj.insert(
+                    "fuzzy_transpositions".to_string(),
+                    Value::Bool(fuzzy_transpositions),
+                );
+            }
+            if let Some(prefix_length) = self.prefix_length {
+                field_obj.insert(
+                    "prefix_length".to_string(),
+                    Value::Number(prefix_length.into()),
+                );
+            }
+            if let Some(max_expansions) = self.max_expansions {
+                field_obj.insert(
+                    "max_expansions".to_string(),
+                    Value::Number(max_expansions.into()),
                 );
             }
 
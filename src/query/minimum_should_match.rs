@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The `minimum_should_match` value of a bool or match query
+#[derive(Debug, Clone, PartialEq)]
+pub enum MinimumShouldMatch {
+    /// A fixed number of clauses, negative to count the clauses allowed to be missing
+    Count(i32),
+    /// A percentage of the clauses, negative to count the percentage allowed to be missing
+    Percentage(f32),
+    /// A conditional specification such as `3<90%`, kept as written
+    Combination(String),
+}
+
+/// Error returned when a `minimum_should_match` string is not well-formed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimumShouldMatchError(pub String);
+
+impl Display for MinimumShouldMatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid minimum_should_match value: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for MinimumShouldMatchError {}
+
+impl MinimumShouldMatch {
+    /// Parse and validate a `minimum_should_match` string, e.g. `"3"`, `"-25%"` or `"2<-25% 9<-3"`
+    pub fn parse(value: &str) -> Result<Self, MinimumShouldMatchError> {
+        let value = value.trim();
+        let invalid = || MinimumShouldMatchError(value.to_string());
+
+        if value.contains('<') {
+            let valid = value.split_whitespace().all(|condition| {
+                condition.split_once('<').is_some_and(|(clauses, spec)| {
+                    clauses.parse::<u32>().is_ok() && Self::parse_simple(spec).is_some()
+                })
+            });
+            return if valid {
+                Ok(MinimumShouldMatch::Combination(value.to_string()))
+            } else {
+                Err(invalid())
+            };
+        }
+
+        Self::parse_simple(value).ok_or_else(invalid)
+    }
+
+    fn parse_simple(value: &str) -> Option<Self> {
+        match value.strip_suffix('%') {
+            Some(percentage) => percentage
+                .parse::<f32>()
+                .ok()
+                .filter(|p| p.is_finite() && (-100.0..=100.0).contains(p))
+                .map(MinimumShouldMatch::Percentage),
+            None => value.parse::<i32>().ok().map(MinimumShouldMatch::Count),
+        }
+    }
+}
+
+impl Display for MinimumShouldMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinimumShouldMatch::Count(count) => write!(f, "{count}"),
+            MinimumShouldMatch::Percentage(percentage) => write!(f, "{percentage}%"),
+            MinimumShouldMatch::Combination(combination) => f.write_str(combination),
+        }
+    }
+}
+
+impl From<i32> for MinimumShouldMatch {
+    fn from(count: i32) -> Self {
+        MinimumShouldMatch::Count(count)
+    }
+}
+
+/// Strings are parsed when possible. Strings that fail validation are passed through unchanged
+/// as a [`MinimumShouldMatch::Combination`]; use [`MinimumShouldMatch::parse`] to reject them.
+impl From<&str> for MinimumShouldMatch {
+    fn from(value: &str) -> Self {
+        MinimumShouldMatch::parse(value)
+            .unwrap_or_else(|_| MinimumShouldMatch::Combination(value.to_string()))
+    }
+}
+
+impl From<String> for MinimumShouldMatch {
+    fn from(value: String) -> Self {
+        MinimumShouldMatch::from(value.as_str())
+    }
+}
+
+impl From<&MinimumShouldMatch> for Value {
+    fn from(minimum_should_match: &MinimumShouldMatch) -> Self {
+        match minimum_should_match {
+            MinimumShouldMatch::Count(count) => Value::Number((*count).into()),
+            other => Value::String(other.to_string()),
+        }
+    }
+}
+
+impl From<MinimumShouldMatch> for Value {
+    fn from(minimum_should_match: MinimumShouldMatch) -> Self {
+        Value::from(&minimum_should_match)
+    }
+}
+
+impl From<MinimumShouldMatch> for Cow<'static, str> {
+    fn from(minimum_should_match: MinimumShouldMatch) -> Self {
+        match minimum_should_match {
+            MinimumShouldMatch::Combination(combination) => Cow::Owned(combination),
+            other => Cow::Owned(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for MinimumShouldMatch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Value::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test;
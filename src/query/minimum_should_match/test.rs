@@ -0,0 +1,72 @@
+use super::*;
+use crate::{BoolQuery, MatchQuery, QueryType, ToOpenSearchJson};
+
+#[test]
+fn test_parse_percentage() {
+    assert_eq!(
+        MinimumShouldMatch::parse("75%"),
+        Ok(MinimumShouldMatch::Percentage(75.0))
+    );
+    assert_eq!(
+        Value::from(MinimumShouldMatch::parse("75%").unwrap()),
+        "75%"
+    );
+}
+
+#[test]
+fn test_parse_negative_count() {
+    assert_eq!(
+        MinimumShouldMatch::parse("-2"),
+        Ok(MinimumShouldMatch::Count(-2))
+    );
+    assert_eq!(Value::from(MinimumShouldMatch::Count(-2)), -2);
+}
+
+#[test]
+fn test_parse_combination() {
+    assert_eq!(
+        MinimumShouldMatch::parse("3<90%"),
+        Ok(MinimumShouldMatch::Combination("3<90%".to_string()))
+    );
+    assert!(MinimumShouldMatch::parse("2<-25% 9<-3").is_ok());
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(MinimumShouldMatch::parse("abc").is_err());
+    assert!(MinimumShouldMatch::parse("150%").is_err());
+    assert!(MinimumShouldMatch::parse("3<").is_err());
+    assert!(MinimumShouldMatch::parse("x<90%").is_err());
+}
+
+#[test]
+fn test_minimum_should_match_on_bool_and_match() {
+    let bool_query = BoolQuery::new()
+        .should(QueryType::term("a", "a"))
+        .minimum_should_match("75%");
+    assert_eq!(
+        bool_query.to_json()["bool"]["minimum_should_match"],
+        serde_json::json!("75%")
+    );
+
+    let bool_query = BoolQuery::new()
+        .should(QueryType::term("a", "a"))
+        .minimum_should_match(1);
+    assert_eq!(
+        bool_query.to_json()["bool"]["minimum_should_match"],
+        serde_json::json!(1)
+    );
+
+    let match_query = MatchQuery::new("title", "quick brown fox").minimum_should_match(2);
+    assert_eq!(
+        match_query.to_json(),
+        serde_json::json!({
+            "match": {
+                "title": {
+                    "query": "quick brown fox",
+                    "minimum_should_match": 2
+                }
+            }
+        })
+    );
+}
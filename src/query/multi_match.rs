@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{MinimumShouldMatch, QueryType, ToOpenSearchJson};
+
+/// How a multi match query combines the fields it searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiMatchType {
+    /// Score by the best matching field, the default
+    BestFields,
+    /// Sum the scores of every matching field
+    MostFields,
+    /// Treat the fields as one big field
+    CrossFields,
+    /// Run a match phrase query on each field
+    Phrase,
+    /// Run a match phrase prefix query on each field
+    PhrasePrefix,
+    /// Run a match bool prefix query on each field
+    BoolPrefix,
+}
+
+impl MultiMatchType {
+    /// The type name as sent to OpenSearch
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MultiMatchType::BestFields => "best_fields",
+            MultiMatchType::MostFields => "most_fields",
+            MultiMatchType::CrossFields => "cross_fields",
+            MultiMatchType::Phrase => "phrase",
+            MultiMatchType::PhrasePrefix => "phrase_prefix",
+            MultiMatchType::BoolPrefix => "bool_prefix",
+        }
+    }
+}
+
+/// Multi Match Query
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiMatchQuery<'a> {
+    /// The query string
+    #[serde(borrow)]
+    pub query: Cow<'a, str>,
+    /// The fields to search, optionally boosted with `^`, e.g. `title^3`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub fields: Vec<Cow<'a, str>>,
+    /// How the fields are combined
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub multi_match_type: Option<MultiMatchType>,
+    /// The operator to use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<Cow<'a, str>>,
+    /// The fuzziness value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzziness: Option<Cow<'a, str>>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+    /// The minimum should match value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_should_match: Option<MinimumShouldMatch>,
+}
+
+impl<'a> MultiMatchQuery<'a> {
+    /// Create a new MultiMatchQuery with a given query string and fields
+    pub fn new<I>(query: impl Into<Cow<'a, str>>, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        Self {
+            query: query.into(),
+            fields: fields.into_iter().map(|f| f.into()).collect(),
+            multi_match_type: None,
+            operator: None,
+            fuzziness: None,
+            boost: None,
+            minimum_should_match: None,
+        }
+    }
+
+    /// Set how the fields are combined
+    pub fn multi_match_type(mut self, multi_match_type: MultiMatchType) -> Self {
+        self.multi_match_type = Some(multi_match_type);
+        self
+    }
+
+    /// Set the operator to use
+    pub fn operator(mut self, operator: impl Into<Cow<'a, str>>) -> Self {
+        self.operator = Some(operator.into());
+        self
+    }
+
+    /// Set the fuzziness value
+    pub fn fuzziness(mut self, fuzziness: impl Into<Cow<'a, str>>) -> Self {
+        self.fuzziness = Some(fuzziness.into());
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Set the minimum should match value
+    pub fn minimum_should_match(
+        mut self,
+        minimum_should_match: impl Into<MinimumShouldMatch>,
+    ) -> Self {
+        self.minimum_should_match = Some(minimum_should_match.into());
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> MultiMatchQuery<'static> {
+        MultiMatchQuery {
+            query: Cow::Owned(self.query.to_string()),
+            fields: self
+                .fields
+                .iter()
+                .map(|f| Cow::Owned(f.to_string()))
+                .collect(),
+            multi_match_type: self.multi_match_type,
+            operator: self.operator.as_ref().map(|o| Cow::Owned(o.to_string())),
+            fuzziness: self.fuzziness.as_ref().map(|f| Cow::Owned(f.to_string())),
+            boost: self.boost,
+            minimum_should_match: self.minimum_should_match.clone(),
+        }
+    }
+}
+
+impl<'a> From<MultiMatchQuery<'a>> for QueryType<'a> {
+    fn from(multi_match_query: MultiMatchQuery<'a>) -> Self {
+        QueryType::MultiMatch(multi_match_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for MultiMatchQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut multi_match_obj = Map::new();
+        multi_match_obj.insert("query".to_string(), Value::String(self.query.to_string()));
+
+        if !self.fields.is_empty() {
+            let fields: Vec<Value> = self
+                .fields
+                .iter()
+                .map(|f| Value::String(f.to_string()))
+                .collect();
+            multi_match_obj.insert("fields".to_string(), Value::Array(fields));
+        }
+        if let Some(multi_match_type) = self.multi_match_type {
+            multi_match_obj.insert(
+                "type".to_string(),
+                Value::String(multi_match_type.as_str().to_string()),
+            );
+        }
+        if let Some(ref operator) = self.operator {
+            multi_match_obj.insert("operator".to_string(), Value::String(operator.to_string()));
+        }
+        if let Some(ref fuzziness) = self.fuzziness {
+            multi_match_obj.insert(
+                "fuzziness".to_string(),
+                Value::String(fuzziness.to_string()),
+            );
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
+            multi_match_obj.insert("boost".to_string(), boost.into());
+        }
+        if let Some(ref minimum_should_match) = self.minimum_should_match {
+            multi_match_obj.insert(
+                "minimum_should_match".to_string(),
+                minimum_should_match.into(),
+            );
+        }
+
+        let mut result = Map::new();
+        result.insert("multi_match".to_string(), Value::Object(multi_match_obj));
+        Value::Object(result)
+    }
+}
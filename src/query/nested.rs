@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Nested Query
+#[derive(Debug, Clone, Serialize)]
+pub struct NestedQuery<'a> {
+    /// The path to the nested object
+    #[serde(borrow)]
+    pub path: Cow<'a, str>,
+    /// The query to run against the nested objects
+    #[serde(borrow)]
+    pub query: Box<QueryType<'a>>,
+    /// How the scores of matching nested objects are combined (avg, max, min, sum, none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub score_mode: Option<Cow<'a, str>>,
+    /// Whether to ignore an unmapped path instead of failing the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_unmapped: Option<bool>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> NestedQuery<'a> {
+    /// Create a new NestedQuery with a given path and query
+    pub fn new(path: impl Into<Cow<'a, str>>, query: QueryType<'a>) -> Self {
+        Self {
+            path: path.into(),
+            query: Box::new(query),
+            score_mode: None,
+            ignore_unmapped: None,
+            boost: None,
+        }
+    }
+
+    /// Set the score mode
+    pub fn score_mode(mut self, score_mode: impl Into<Cow<'a, str>>) -> Self {
+        self.score_mode = Some(score_mode.into());
+        self
+    }
+
+    /// Set whether to ignore an unmapped path instead of failing the query
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> NestedQuery<'static> {
+        NestedQuery {
+            path: Cow::Owned(self.path.to_string()),
+            query: Box::new((*self.query).to_owned()),
+            score_mode: self.score_mode.as_ref().map(|s| Cow::Owned(s.to_string())),
+            ignore_unmapped: self.ignore_unmapped,
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<NestedQuery<'a>> for QueryType<'a> {
+    fn from(nested_query: NestedQuery<'a>) -> Self {
+        QueryType::Nested(nested_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for NestedQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut nested_obj = Map::new();
+        nested_obj.insert("path".to_string(), Value::String(self.path.to_string()));
+        nested_obj.insert("query".to_string(), self.query.to_json());
+
+        if let Some(score_mode) = self.score_mode.as_ref() {
+            nested_obj.insert(
+                "score_mode".to_string(),
+                Value::String(score_mode.to_string()),
+            );
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped {
+            nested_obj.insert("ignore_unmapped".to_string(), Value::Bool(ignore_unmapped));
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
+            nested_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("nested".to_string(), Value::Object(nested_obj));
+        Value::Object(result)
+    }
+}
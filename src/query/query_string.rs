@@ -0,0 +1,201 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, ToOpenSearchJson};
+
+/// Query String Query
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryStringQuery<'a> {
+    /// The query string, in Lucene query syntax
+    #[serde(borrow)]
+    pub query: Cow<'a, str>,
+    /// The field to search when no field is given in the query string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_field: Option<Cow<'a, str>>,
+    /// The fields to search when no field is given in the query string
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub fields: Vec<Cow<'a, str>>,
+    /// The default operator between terms (`OR` or `AND`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_operator: Option<Cow<'a, str>>,
+    /// The analyzer to use for the query string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analyzer: Option<Cow<'a, str>>,
+    /// The analyzer to use for quoted text in the query string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_analyzer: Option<Cow<'a, str>>,
+    /// The slop value for phrases in the query string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phrase_slop: Option<u32>,
+    /// A suffix appended to fields for quoted text, e.g. `.exact`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_field_suffix: Option<Cow<'a, str>>,
+    /// The boost value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<f64>,
+}
+
+impl<'a> QueryStringQuery<'a> {
+    /// Create a new QueryStringQuery with a given query string
+    pub fn new(query: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            query: query.into(),
+            default_field: None,
+            fields: Vec::new(),
+            default_operator: None,
+            analyzer: None,
+            quote_analyzer: None,
+            phrase_slop: None,
+            quote_field_suffix: None,
+            boost: None,
+        }
+    }
+
+    /// Set the field to search when no field is given in the query string
+    pub fn default_field(mut self, default_field: impl Into<Cow<'a, str>>) -> Self {
+        self.default_field = Some(default_field.into());
+        self
+    }
+
+    /// Set the fields to search when no field is given in the query string
+    pub fn fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        self.fields = fields.into_iter().map(|f| f.into()).collect();
+        self
+    }
+
+    /// Set the default operator between terms
+    pub fn default_operator(mut self, default_operator: impl Into<Cow<'a, str>>) -> Self {
+        self.default_operator = Some(default_operator.into());
+        self
+    }
+
+    /// Set the analyzer to use for the query string
+    pub fn analyzer(mut self, analyzer: impl Into<Cow<'a, str>>) -> Self {
+        self.analyzer = Some(analyzer.into());
+        self
+    }
+
+    /// Set the analyzer to use for quoted text in the query string
+    pub fn quote_analyzer(mut self, quote_analyzer: impl Into<Cow<'a, str>>) -> Self {
+        self.quote_analyzer = Some(quote_analyzer.into());
+        self
+    }
+
+    /// Set the slop value for phrases in the query string
+    pub fn phrase_slop(mut self, phrase_slop: u32) -> Self {
+        self.phrase_slop = Some(phrase_slop);
+        self
+    }
+
+    /// Set the suffix appended to fields for quoted text
+    pub fn quote_field_suffix(mut self, quote_field_suffix: impl Into<Cow<'a, str>>) -> Self {
+        self.quote_field_suffix = Some(quote_field_suffix.into());
+        self
+    }
+
+    /// Set the boost value
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> QueryStringQuery<'static> {
+        QueryStringQuery {
+            query: Cow::Owned(self.query.to_string()),
+            default_field: self
+                .default_field
+                .as_ref()
+                .map(|f| Cow::Owned(f.to_string())),
+            fields: self
+                .fields
+                .iter()
+                .map(|f| Cow::Owned(f.to_string()))
+                .collect(),
+            default_operator: self
+                .default_operator
+                .as_ref()
+                .map(|o| Cow::Owned(o.to_string())),
+            analyzer: self.analyzer.as_ref().map(|a| Cow::Owned(a.to_string())),
+            quote_analyzer: self
+                .quote_analyzer
+                .as_ref()
+                .map(|a| Cow::Owned(a.to_string())),
+            phrase_slop: self.phrase_slop,
+            quote_field_suffix: self
+                .quote_field_suffix
+                .as_ref()
+                .map(|s| Cow::Owned(s.to_string())),
+            boost: self.boost,
+        }
+    }
+}
+
+impl<'a> From<QueryStringQuery<'a>> for QueryType<'a> {
+    fn from(query_string_query: QueryStringQuery<'a>) -> Self {
+        QueryType::QueryString(query_string_query)
+    }
+}
+
+impl<'a> ToOpenSearchJson for QueryStringQuery<'a> {
+    fn to_json(&self) -> Value {
+        let mut query_string_obj = Map::new();
+        query_string_obj.insert("query".to_string(), Value::String(self.query.to_string()));
+
+        if let Some(ref default_field) = self.default_field {
+            query_string_obj.insert(
+                "default_field".to_string(),
+                Value::String(default_field.to_string()),
+            );
+        }
+        if !self.fields.is_empty() {
+            let fields: Vec<Value> = self
+                .fields
+                .iter()
+                .map(|f| Value::String(f.to_string()))
+                .collect();
+            query_string_obj.insert("fields".to_string(), Value::Array(fields));
+        }
+        if let Some(ref default_operator) = self.default_operator {
+            query_string_obj.insert(
+                "default_operator".to_string(),
+                Value::String(default_operator.to_string()),
+            );
+        }
+        if let Some(ref analyzer) = self.analyzer {
+            query_string_obj.insert("analyzer".to_string(), Value::String(analyzer.to_string()));
+        }
+        if let Some(ref quote_analyzer) = self.quote_analyzer {
+            query_string_obj.insert(
+                "quote_analyzer".to_string(),
+                Value::String(quote_analyzer.to_string()),
+            );
+        }
+        if let Some(phrase_slop) = self.phrase_slop {
+            query_string_obj.insert("phrase_slop".to_string(), Value::Number(phrase_slop.into()));
+        }
+        if let Some(ref quote_field_suffix) = self.quote_field_suffix {
+            query_string_obj.insert(
+                "quote_field_suffix".to_string(),
+                Value::String(quote_field_suffix.to_string()),
+            );
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
+            query_string_obj.insert("boost".to_string(), boost.into());
+        }
+
+        let mut result = Map::new();
+        result.insert("query_string".to_string(), Value::Object(query_string_obj));
+        Value::Object(result)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_query_string_basic() {
+    let query = QueryStringQuery::new("title:rust AND status:published");
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "query_string": {
+                "query": "title:rust AND status:published"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_query_string_phrase_slop() {
+    let query = QueryStringQuery::new("\"quick fox\"")
+        .default_field("body")
+        .phrase_slop(2);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "query_string": {
+                "query": "\"quick fox\"",
+                "default_field": "body",
+                "phrase_slop": 2
+            }
+        })
+    );
+}
+
+#[test]
+fn test_query_string_quote_field_suffix() {
+    let query = QueryStringQuery::new("\"quick fox\" jumps")
+        .fields(["title", "body"])
+        .analyzer("english")
+        .quote_analyzer("standard")
+        .quote_field_suffix(".exact");
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "query_string": {
+                "query": "\"quick fox\" jumps",
+                "fields": ["title", "body"],
+                "analyzer": "english",
+                "quote_analyzer": "standard",
+                "quote_field_suffix": ".exact"
+            }
+        })
+    );
+}
@@ -3,7 +3,8 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
-use crate::{QueryType, ToOpenSearchJson};
+use crate::util::finite;
+use crate::{DateFormat, QueryType, ToOpenSearchJson};
 
 /// Range Query
 #[derive(Debug, Clone, Serialize)]
@@ -23,6 +24,9 @@ pub struct RangeQuery<'a> {
     /// Less than
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lt: Option<Value>,
+    /// The date format used to parse the bounds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<DateFormat<'a>>,
     /// The boost value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
@@ -37,6 +41,7 @@ impl<'a> RangeQuery<'a> {
             gt: None,
             lte: None,
             lt: None,
+            format: None,
             boost: None,
         }
     }
@@ -62,6 +67,12 @@ impl<'a> RangeQuery<'a> {
         self
     }
 
+    /// Set the date format used to parse the bounds
+    pub fn format(mut self, format: impl Into<DateFormat<'a>>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
     /// Set the boost value
     pub fn boost(mut self, boost: f64) -> Self {
         self.boost = Some(boost);
@@ -76,6 +87,7 @@ impl<'a> RangeQuery<'a> {
             gt: self.gt.clone(),
             lte: self.lte.clone(),
             lt: self.lt.clone(),
+            format: self.format.as_ref().map(|f| f.to_owned()),
             boost: self.boost,
         }
     }
@@ -104,7 +116,10 @@ impl<'a> ToOpenSearchJson for RangeQuery<'a> {
         if let Some(ref lt) = self.lt {
             field_obj.insert("lt".to_string(), lt.clone());
         }
-        if let Some(boost) = self.boost {
+        if let Some(ref format) = self.format {
+            field_obj.insert("format".to_string(), Value::String(format.to_string()));
+        }
+        if let Some(boost) = self.boost.and_then(finite) {
             field_obj.insert("boost".to_string(), boost.into());
         }
 
@@ -129,6 +144,8 @@ pub struct RangeQueryBuilder<'a> {
     pub lte: Option<Value>,
     /// Less than
     pub lt: Option<Value>,
+    /// The date format used to parse the bounds
+    pub format: Option<DateFormat<'a>>,
     /// The boost value
     pub boost: Option<f64>,
 }
@@ -142,6 +159,7 @@ impl<'a> RangeQueryBuilder<'a> {
             gt: None,
             lte: None,
             lt: None,
+            format: None,
             boost: None,
         }
     }
@@ -170,6 +188,12 @@ impl<'a> RangeQueryBuilder<'a> {
         self
     }
 
+    /// Set the date format used to parse the bounds
+    pub fn format(&mut self, format: impl Into<DateFormat<'a>>) -> &mut Self {
+        self.format = Some(format.into());
+        self
+    }
+
     /// Set the boost value
     pub fn boost(&mut self, boost: f64) -> &mut Self {
         self.boost = Some(boost);
@@ -184,6 +208,7 @@ impl<'a> RangeQueryBuilder<'a> {
             gt: self.gt,
             lte: self.lte,
             lt: self.lt,
+            format: self.format,
             boost: self.boost,
         }
     }
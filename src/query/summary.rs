@@ -0,0 +1,76 @@
+use crate::QueryType;
+
+impl<'a> QueryType<'a> {
+    /// A compact, one-line description of the query's structure for logging, e.g.
+    /// `bool(must=2, must_not=0, should=1, filter=0)` or `term(status)`.
+    ///
+    /// Values are left out so the summary is safe to log.
+    pub fn summary(&self) -> String {
+        match self {
+            QueryType::Bool(bool_query) => format!(
+                "bool(must={}, must_not={}, should={}, filter={})",
+                bool_query.must.len(),
+                bool_query.must_not.len(),
+                bool_query.should.len(),
+                bool_query.filter.len()
+            ),
+            QueryType::ConstantScore(constant_score) => {
+                format!("constant_score({})", constant_score.filter.summary())
+            }
+            QueryType::DisMax(dis_max) => format!("dis_max(queries={})", dis_max.queries.len()),
+            QueryType::FunctionScore(function_score) => format!(
+                "function_score(functions={})",
+                function_score.functions.len()
+            ),
+            QueryType::HasChild(has_child) => format!("has_child({})", has_child.child_type),
+            QueryType::HasParent(has_parent) => format!("has_parent({})", has_parent.parent_type),
+            QueryType::Nested(nested) => format!("nested({})", nested.path),
+            QueryType::MultiMatch(multi_match) => {
+                format!("multi_match(fields={})", multi_match.fields.len())
+            }
+            QueryType::QueryString(query_string) => {
+                format!("query_string(fields={})", query_string.fields.len())
+            }
+            QueryType::MatchAll(_) | QueryType::MatchNone | QueryType::Raw(_) => {
+                self.kind_name().to_string()
+            }
+            QueryType::GeoDistance(_)
+            | QueryType::MatchPhrase(_)
+            | QueryType::MatchPhrasePrefix(_)
+            | QueryType::Match(_)
+            | QueryType::Range(_)
+            | QueryType::Regexp(_)
+            | QueryType::Term(_)
+            | QueryType::Terms(_)
+            | QueryType::WildCard(_) => {
+                format!("{}({})", self.kind_name(), self.field().unwrap_or_default())
+            }
+        }
+    }
+
+    pub(crate) fn kind_name(&self) -> &'static str {
+        match self {
+            QueryType::Bool(_) => "bool",
+            QueryType::ConstantScore(_) => "constant_score",
+            QueryType::DisMax(_) => "dis_max",
+            QueryType::FunctionScore(_) => "function_score",
+            QueryType::GeoDistance(_) => "geo_distance",
+            QueryType::HasChild(_) => "has_child",
+            QueryType::HasParent(_) => "has_parent",
+            QueryType::MatchAll(_) => "match_all",
+            QueryType::MatchNone => "match_none",
+            QueryType::MatchPhrase(_) => "match_phrase",
+            QueryType::MatchPhrasePrefix(_) => "match_phrase_prefix",
+            QueryType::Match(_) => "match",
+            QueryType::MultiMatch(_) => "multi_match",
+            QueryType::Nested(_) => "nested",
+            QueryType::QueryString(_) => "query_string",
+            QueryType::Range(_) => "range",
+            QueryType::Regexp(_) => "regexp",
+            QueryType::Term(_) => "term",
+            QueryType::Terms(_) => "terms",
+            QueryType::WildCard(_) => "wildcard",
+            QueryType::Raw(_) => "raw",
+        }
+    }
+}
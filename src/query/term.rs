@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Term Query
@@ -16,6 +17,12 @@ pub struct TermQuery<'a> {
     /// The boost value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
+    /// Whether to match the value case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case_insensitive: Option<bool>,
+    /// The name of the query, reported in `matched_queries` of each hit
+    #[serde(skip_serializing_if = "Option::is_none", rename = "_name")]
+    pub name: Option<Cow<'a, str>>,
 }
 
 impl<'a> TermQuery<'a> {
@@ -25,6 +32,8 @@ impl<'a> TermQuery<'a> {
             field: field.into(),
             value: value.into(),
             boost: None,
+            case_insensitive: None,
+            name: None,
         }
     }
 
@@ -34,12 +43,26 @@ impl<'a> TermQuery<'a> {
         self
     }
 
+    /// Set whether to match the value case-insensitively
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = Some(case_insensitive);
+        self
+    }
+
+    /// Set the name of the query
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> TermQuery<'static> {
         TermQuery {
             field: Cow::Owned(self.field.to_string()),
             value: self.value.clone(),
             boost: self.boost,
+            case_insensitive: self.case_insensitive,
+            name: self.name.as_ref().map(|n| Cow::Owned(n.to_string())),
         }
     }
 }
@@ -56,12 +79,24 @@ impl<'a> ToOpenSearchJson for TermQuery<'a> {
         let mut term_obj = Map::new();
 
         // If we have additional parameters like boost, use the object form
-        if self.boost.is_some() {
+        if self.boost.and_then(finite).is_some()
+            || self.case_insensitive.is_some()
+            || self.name.is_some()
+        {
             let mut field_obj = Map::new();
             field_obj.insert("value".to_string(), self.value.clone());
-            if let Some(boost) = self.boost {
+            if let Some(boost) = self.boost.and_then(finite) {
                 field_obj.insert("boost".to_string(), boost.into());
             }
+            if let Some(case_insensitive) = self.case_insensitive {
+                field_obj.insert(
+                    "case_insensitive".to_string(),
+                    Value::Bool(case_insensitive),
+                );
+            }
+            if let Some(ref name) = self.name {
+                field_obj.insert("_name".to_string(), Value::String(name.to_string()));
+            }
             term_obj.insert(self.field.to_string(), Value::Object(field_obj));
         } else {
             // Simple form: just field: value
@@ -72,3 +107,6 @@ impl<'a> ToOpenSearchJson for TermQuery<'a> {
         Value::Object(result)
     }
 }
+
+#[cfg(test)]
+mod test;
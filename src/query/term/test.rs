@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_term_query_simple_form() {
+    let query = TermQuery::new("status", "published");
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({"term": {"status": "published"}})
+    );
+}
+
+#[test]
+fn test_term_query_boost_forces_object_form() {
+    let query = TermQuery::new("status", "published").boost(2.0);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "term": {
+                "status": {
+                    "value": "published",
+                    "boost": 2.0
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_term_query_name_forces_object_form() {
+    let query = TermQuery::new("status", "published").name("is_published");
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "term": {
+                "status": {
+                    "value": "published",
+                    "_name": "is_published"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_term_query_case_insensitive() {
+    let query = TermQuery::new("status", "X").case_insensitive(true);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({"term": {"status": {"value": "X", "case_insensitive": true}}})
+    );
+}
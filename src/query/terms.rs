@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Terms Query
@@ -14,9 +15,15 @@ pub struct TermsQuery<'a> {
     /// The values to search for
     #[serde(borrow)]
     pub values: Cow<'a, [Value]>,
+    /// Fetch the values from another document instead; takes precedence over `values`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup: Option<TermsLookup<'a>>,
     /// The boost value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
+    /// Whether to match the values case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case_insensitive: Option<bool>,
 }
 
 impl<'a> TermsQuery<'a> {
@@ -28,7 +35,31 @@ impl<'a> TermsQuery<'a> {
         Self {
             field: field.into(),
             values: Cow::Owned(values.into_iter().map(|v| v.into()).collect()),
+            lookup: None,
+            boost: None,
+            case_insensitive: None,
+        }
+    }
+
+    /// Create a new TermsQuery borrowing its values, so large value lists aren't copied
+    pub fn borrowed(field: impl Into<Cow<'a, str>>, values: &'a [Value]) -> Self {
+        Self {
+            field: field.into(),
+            values: Cow::Borrowed(values),
+            lookup: None,
             boost: None,
+            case_insensitive: None,
+        }
+    }
+
+    /// Create a new TermsQuery that fetches its values from a field of another document
+    pub fn lookup(field: impl Into<Cow<'a, str>>, lookup: TermsLookup<'a>) -> Self {
+        Self {
+            field: field.into(),
+            values: Cow::Borrowed(&[]),
+            lookup: Some(lookup),
+            boost: None,
+            case_insensitive: None,
         }
     }
 
@@ -38,12 +69,20 @@ impl<'a> TermsQuery<'a> {
         self
     }
 
+    /// Set whether to match the values case-insensitively
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = Some(case_insensitive);
+        self
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> TermsQuery<'static> {
         TermsQuery {
             field: Cow::Owned(self.field.to_string()),
             values: Cow::Owned(self.values.to_vec()),
+            lookup: self.lookup.as_ref().map(|l| l.to_owned()),
             boost: self.boost,
+            case_insensitive: self.case_insensitive,
         }
     }
 }
@@ -59,20 +98,89 @@ impl<'a> ToOpenSearchJson for TermsQuery<'a> {
         let mut result = Map::new();
         let mut terms_obj = Map::new();
 
-        if self.boost.is_some() {
-            // Complex form with boost
-            let mut field_obj = Map::new();
-            field_obj.insert("terms".to_string(), Value::Array(self.values.to_vec()));
-            if let Some(boost) = self.boost {
-                field_obj.insert("boost".to_string(), boost.into());
-            }
-            terms_obj.insert(self.field.to_string(), Value::Object(field_obj));
-        } else {
-            // Simple form: field: [values]
-            terms_obj.insert(self.field.to_string(), Value::Array(self.values.to_vec()));
+        // The values, or the lookup that fetches them, go under the field, with the boost and
+        // case_insensitive flag alongside the field
+        let values = match self.lookup {
+            Some(ref lookup) => lookup.to_json(),
+            None => Value::Array(self.values.to_vec()),
+        };
+        terms_obj.insert(self.field.to_string(), values);
+        if let Some(boost) = self.boost.and_then(finite) {
+            terms_obj.insert("boost".to_string(), boost.into());
+        }
+        if let Some(case_insensitive) = self.case_insensitive {
+            terms_obj.insert(
+                "case_insensitive".to_string(),
+                Value::Bool(case_insensitive),
+            );
         }
 
         result.insert("terms".to_string(), Value::Object(terms_obj));
         Value::Object(result)
     }
 }
+
+/// The location of the values for a terms lookup
+#[derive(Debug, Clone, Serialize)]
+pub struct TermsLookup<'a> {
+    /// The index to fetch the document from
+    #[serde(borrow)]
+    pub index: Cow<'a, str>,
+    /// The id of the document to fetch
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    /// The field of the document holding the values
+    #[serde(borrow)]
+    pub path: Cow<'a, str>,
+    /// Custom routing value of the document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing: Option<Cow<'a, str>>,
+}
+
+impl<'a> TermsLookup<'a> {
+    /// Create a new TermsLookup
+    pub fn new(
+        index: impl Into<Cow<'a, str>>,
+        id: impl Into<Cow<'a, str>>,
+        path: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            index: index.into(),
+            id: id.into(),
+            path: path.into(),
+            routing: None,
+        }
+    }
+
+    /// Set the custom routing value of the document
+    pub fn routing(mut self, routing: impl Into<Cow<'a, str>>) -> Self {
+        self.routing = Some(routing.into());
+        self
+    }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> TermsLookup<'static> {
+        TermsLookup {
+            index: Cow::Owned(self.index.to_string()),
+            id: Cow::Owned(self.id.to_string()),
+            path: Cow::Owned(self.path.to_string()),
+            routing: self.routing.as_ref().map(|r| Cow::Owned(r.to_string())),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for TermsLookup<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("index".to_string(), Value::String(self.index.to_string()));
+        result.insert("id".to_string(), Value::String(self.id.to_string()));
+        result.insert("path".to_string(), Value::String(self.path.to_string()));
+        if let Some(ref routing) = self.routing {
+            result.insert("routing".to_string(), Value::String(routing.to_string()));
+        }
+        Value::Object(result)
+    }
+}
+
+#[cfg(test)]
+mod test;
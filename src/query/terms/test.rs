@@ -0,0 +1,93 @@
+use super::*;
+
+#[test]
+fn test_terms_query() {
+    let query = TermsQuery::new("status", ["published", "draft"]);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "terms": {
+                "status": ["published", "draft"]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_lookup() {
+    let query = QueryType::terms_lookup(
+        "user_id",
+        TermsLookup::new("followers", "42", "followed_ids").routing("42"),
+    );
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "terms": {
+                "user_id": {
+                    "index": "followers",
+                    "id": "42",
+                    "path": "followed_ids",
+                    "routing": "42"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_lookup_with_boost() {
+    let query = TermsQuery::lookup(
+        "user_id",
+        TermsLookup::new("followers", "42", "followed_ids"),
+    )
+    .boost(2.0);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "terms": {
+                "user_id": {
+                    "index": "followers",
+                    "id": "42",
+                    "path": "followed_ids"
+                },
+                "boost": 2.0
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_query_case_insensitive() {
+    let query = TermsQuery::new("status", ["X", "Y"]).case_insensitive(true);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "terms": {
+                "status": ["X", "Y"],
+                "case_insensitive": true
+            }
+        })
+    );
+
+    // The lookup form keeps the flag too
+    let query = TermsQuery::lookup("status", TermsLookup::new("statuses", "1", "allowed"))
+        .case_insensitive(true);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "terms": {
+                "status": {
+                    "index": "statuses",
+                    "id": "1",
+                    "path": "allowed"
+                },
+                "case_insensitive": true
+            }
+        })
+    );
+}
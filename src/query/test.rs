@@ -0,0 +1,520 @@
+use super::*;
+use crate::{Distance, DistanceUnit, GeoPoint};
+
+#[test]
+fn test_raw_query() {
+    let raw = serde_json::json!({"match_all": {}});
+    let query = QueryType::raw(raw.clone());
+
+    assert_eq!(query.to_json(), raw);
+    assert_eq!(query.to_owned().to_json(), raw);
+}
+
+#[test]
+fn test_as_cached_json() {
+    let raw = serde_json::json!({"match_all": {}});
+
+    assert_eq!(QueryType::raw(raw.clone()).as_cached_json(), Some(&raw));
+    assert_eq!(
+        QueryType::term("status", "published").as_cached_json(),
+        None
+    );
+}
+
+#[test]
+fn test_estimate_cost_regexp_more_expensive_than_term() {
+    let term = QueryType::term("status", "published").estimate_cost();
+    let regexp = QueryType::regexp("status", "pub.*").estimate_cost();
+
+    assert!(regexp.score > term.score);
+    assert!(term.warnings.is_empty());
+    assert_eq!(regexp.warnings, ["regexp query on field \"status\""]);
+}
+
+#[test]
+fn test_estimate_cost_warnings_and_nested_bool() {
+    let leading = QueryType::wildcard("name", "*john", true).estimate_cost();
+    assert_eq!(leading.warnings, ["leading wildcard on field \"name\""]);
+
+    let trailing = QueryType::wildcard("name", "john*", true).estimate_cost();
+    assert!(trailing.warnings.is_empty());
+
+    let inner = BoolQuery::new()
+        .must(QueryType::term("a", "a"))
+        .must(QueryType::term("b", "b"));
+    let flat = QueryType::Bool(inner.clone()).estimate_cost();
+    let nested = QueryType::Bool(BoolQuery::new().must(inner.into())).estimate_cost();
+
+    assert_eq!(flat.score, 3);
+    assert_eq!(nested.score, 1 + 2 * flat.score);
+}
+
+#[test]
+fn test_range_between() {
+    assert_eq!(
+        QueryType::range_between("age", 18, 65).to_json(),
+        serde_json::json!({"range": {"age": {"gte": 18, "lte": 65}}})
+    );
+}
+
+#[test]
+fn test_range_gte_and_lte() {
+    assert_eq!(
+        QueryType::range_gte("published_at", "now-7d").to_json(),
+        serde_json::json!({"range": {"published_at": {"gte": "now-7d"}}})
+    );
+    assert_eq!(
+        QueryType::range_lte("price", 100).to_json(),
+        serde_json::json!({"range": {"price": {"lte": 100}}})
+    );
+}
+
+#[test]
+fn test_with_boost_on_match() {
+    let query = QueryType::Match(MatchQuery::new("title", "rust")).with_boost(2.0);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({"match": {"title": {"query": "rust", "boost": 2.0}}})
+    );
+}
+
+#[test]
+fn test_with_boost_on_range() {
+    let query = QueryType::range_gte("age", 18).with_boost(1.5);
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({"range": {"age": {"gte": 18, "boost": 1.5}}})
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "with_boost called on a query without a boost")]
+fn test_with_boost_on_unsupported_query() {
+    let _ = QueryType::regexp("name", "jo.*").with_boost(2.0);
+}
+
+#[test]
+fn test_match_all() {
+    assert_eq!(
+        QueryType::match_all().to_json(),
+        serde_json::json!({"match_all": {}})
+    );
+}
+
+#[test]
+fn test_match_all_with_boost() {
+    assert_eq!(
+        QueryType::match_all().with_boost(1.2).to_json(),
+        serde_json::json!({"match_all": {"boost": 1.2}})
+    );
+    assert_eq!(
+        QueryType::from(MatchAllQuery::new().boost(1.2)).to_json(),
+        serde_json::json!({"match_all": {"boost": 1.2}})
+    );
+}
+
+#[test]
+fn test_match_none() {
+    assert_eq!(
+        QueryType::match_none().to_json(),
+        serde_json::json!({"match_none": {}})
+    );
+}
+
+#[test]
+fn test_constant_score_and_nested() {
+    let query = QueryType::constant_score(QueryType::term("status", "active")).with_boost(1.5);
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "constant_score": {
+                "filter": {"term": {"status": "active"}},
+                "boost": 1.5
+            }
+        })
+    );
+
+    let query = QueryType::from(
+        NestedQuery::new(
+            "comments",
+            QueryType::match_phrase("comments.text", "great"),
+        )
+        .score_mode("max"),
+    );
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "nested": {
+                "path": "comments",
+                "query": {"match_phrase": {"comments.text": "great"}},
+                "score_mode": "max"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_to_owned_deeply_nested() {
+    let path = String::from("comments");
+    let field = String::from("comments.author");
+    let value = String::from("alice");
+
+    let query = QueryType::constant_score(
+        BoolQuery::new()
+            .must(QueryType::nested(
+                path.as_str(),
+                QueryType::term(field.as_str(), value.as_str()),
+            ))
+            .into(),
+    );
+    let expected = query.to_json();
+
+    let owned: QueryType<'static> = query.to_owned();
+    drop(path);
+    drop(field);
+    drop(value);
+
+    assert_eq!(owned.to_json(), expected);
+}
+
+#[test]
+fn test_ignore_unmapped() {
+    let query = NestedQuery::new("comments", QueryType::match_all());
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "nested": {
+                "path": "comments",
+                "query": {"match_all": {}}
+            }
+        })
+    );
+    assert_eq!(
+        query.ignore_unmapped(true).to_json(),
+        serde_json::json!({
+            "nested": {
+                "path": "comments",
+                "query": {"match_all": {}},
+                "ignore_unmapped": true
+            }
+        })
+    );
+
+    assert_eq!(
+        HasChildQuery::new("answer", QueryType::match_all())
+            .ignore_unmapped(true)
+            .to_json(),
+        serde_json::json!({
+            "has_child": {
+                "type": "answer",
+                "query": {"match_all": {}},
+                "ignore_unmapped": true
+            }
+        })
+    );
+    assert_eq!(
+        HasParentQuery::new("question", QueryType::match_all())
+            .ignore_unmapped(false)
+            .to_json(),
+        serde_json::json!({
+            "has_parent": {
+                "parent_type": "question",
+                "query": {"match_all": {}},
+                "ignore_unmapped": false
+            }
+        })
+    );
+}
+
+#[test]
+fn test_match_query_analyzer() {
+    assert_eq!(
+        MatchQuery::new("title", "quick fox")
+            .analyzer("my_analyzer")
+            .to_json(),
+        serde_json::json!({
+            "match": {
+                "title": {
+                    "query": "quick fox",
+                    "analyzer": "my_analyzer"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_geo_distance() {
+    let point = GeoPoint::new(40.7, -74.0);
+    let expected = serde_json::json!({
+        "geo_distance": {
+            "distance": "12km",
+            "location": {"lat": 40.7, "lon": -74.0}
+        }
+    });
+
+    assert_eq!(
+        GeoDistanceQuery::new(
+            "location",
+            point,
+            Distance::new(12.0, DistanceUnit::Kilometers)
+        )
+        .to_json(),
+        expected
+    );
+    assert_eq!(
+        GeoDistanceQuery::new("location", point, "12km").to_json(),
+        expected
+    );
+}
+
+#[test]
+fn test_walk_collects_fields() {
+    let query = QueryType::from(
+        BoolQuery::new()
+            .must(QueryType::match_phrase("title", "quick fox"))
+            .should(QueryType::constant_score(QueryType::term(
+                "status", "active",
+            )))
+            .filter(QueryType::nested(
+                "comments",
+                QueryType::from(
+                    BoolQuery::new()
+                        .must(QueryType::range_gte("comments.date", "now-1d"))
+                        .must_not(QueryType::wildcard("comments.author", "*bot", false)),
+                ),
+            )),
+    );
+
+    let mut fields = Vec::new();
+    let mut visited = 0;
+    query.walk(&mut |q| {
+        visited += 1;
+        fields.extend(q.field());
+    });
+
+    assert_eq!(visited, 8);
+    assert_eq!(
+        fields,
+        ["title", "status", "comments.date", "comments.author"]
+    );
+}
+
+#[test]
+fn test_map_fields() {
+    let query = QueryType::from(
+        BoolQuery::new()
+            .must(QueryType::Match(MatchQuery::new("title", "fox")))
+            .filter(QueryType::range_gte("age", 18)),
+    );
+
+    let prefixed = query.map_fields(&|field| format!("tenant1.{field}"));
+
+    assert_eq!(
+        prefixed.to_json(),
+        serde_json::json!({
+            "bool": {
+                "must": [{"match": {"tenant1.title": "fox"}}],
+                "filter": [{"range": {"tenant1.age": {"gte": 18}}}]
+            }
+        })
+    );
+    assert_eq!(
+        query.to_json()["bool"]["must"][0],
+        serde_json::json!({"match": {"title": "fox"}})
+    );
+}
+
+#[test]
+fn test_summary() {
+    let query = QueryType::from(
+        BoolQuery::new()
+            .must(QueryType::term("status", "active"))
+            .must(QueryType::range_gte("age", 18))
+            .should(QueryType::match_phrase("title", "quick fox")),
+    );
+    assert_eq!(
+        query.summary(),
+        "bool(must=2, must_not=0, should=1, filter=0)"
+    );
+    assert_eq!(
+        QueryType::term("status", "active").summary(),
+        "term(status)"
+    );
+    assert_eq!(
+        QueryType::nested("comments", QueryType::match_all()).summary(),
+        "nested(comments)"
+    );
+}
+
+#[test]
+fn test_in_filter_context() {
+    assert_eq!(
+        QueryType::range_gte("age", 18)
+            .in_filter_context()
+            .to_json(),
+        serde_json::json!({
+            "bool": {
+                "filter": [{"range": {"age": {"gte": 18}}}]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_filtered() {
+    assert_eq!(
+        QueryType::filtered([
+            QueryType::term("status", "active"),
+            QueryType::range_gte("age", 18),
+        ])
+        .to_json(),
+        serde_json::json!({
+            "bool": {
+                "must": [{"match_all": {}}],
+                "filter": [
+                    {"term": {"status": "active"}},
+                    {"range": {"age": {"gte": 18}}}
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_autocomplete() {
+    let query = QueryType::autocomplete("open", &[("title", 3.0), ("description", 1.0)]);
+
+    let expected = QueryType::Bool(
+        BoolQuery::new()
+            .should(
+                MatchPhrasePrefixQuery::new("title", "open")
+                    .boost(3.0)
+                    .into(),
+            )
+            .should(
+                MatchPhrasePrefixQuery::new("description", "open")
+                    .boost(1.0)
+                    .into(),
+            )
+            .minimum_should_match(1),
+    );
+
+    assert_eq!(query.to_json(), expected.to_json());
+}
+
+#[test]
+fn test_fuzzy_search() {
+    let query = QueryType::fuzzy_search("opnsearch", ["title^2", "body"], "AUTO");
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "multi_match": {
+                "query": "opnsearch",
+                "fields": ["title^2", "body"],
+                "type": "best_fields",
+                "operator": "and",
+                "fuzziness": "AUTO"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_dis_max_query_builder() {
+    let mut builder = QueryType::dis_max();
+    builder
+        .query(QueryType::term("title", "fox"))
+        .query(QueryType::term("body", "fox"))
+        .query(QueryType::term("tags", "fox"))
+        .tie_breaker(0.3);
+    let query = QueryType::DisMax(builder.build());
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "dis_max": {
+                "queries": [
+                    { "term": { "title": "fox" } },
+                    { "term": { "body": "fox" } },
+                    { "term": { "tags": "fox" } }
+                ],
+                "tie_breaker": 0.3
+            }
+        })
+    );
+
+    let query = DisMaxQueryBuilder::with_defaults().build();
+    assert_eq!(query.tie_breaker, Some(0.0));
+}
+
+#[test]
+fn test_terms_ref() {
+    let values: Vec<Value> = (0..1_000)
+        .map(|id| Value::from(format!("user-{id}")))
+        .collect();
+
+    let borrowed = QueryType::terms_ref("user_id", &values);
+    let QueryType::Terms(ref terms) = borrowed else {
+        panic!("expected a terms query");
+    };
+    assert!(matches!(terms.values, Cow::Borrowed(_)));
+    assert!(std::ptr::eq(terms.values.as_ptr(), values.as_ptr()));
+
+    let owned = QueryType::terms("user_id", values.clone());
+    assert_eq!(borrowed.to_json(), owned.to_json());
+}
+
+#[test]
+fn test_into_request() {
+    let request = QueryType::term("a", "b").into_request().size(5);
+
+    assert_eq!(request.validate(), Ok(()));
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": { "term": { "a": "b" } },
+            "size": 5
+        })
+    );
+}
+
+#[test]
+fn test_match_query_fuzzy_options() {
+    let query = QueryType::Match(
+        MatchQuery::new("title", "opnsearch")
+            .fuzziness("AUTO")
+            .prefix_length(2)
+            .max_expansions(20)
+            .fuzzy_transpositions(false)
+            .fuzzy_rewrite("top_terms_10")
+            .lenient(true),
+    );
+
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!({
+            "match": {
+                "title": {
+                    "query": "opnsearch",
+                    "fuzziness": "AUTO",
+                    "prefix_length": 2,
+                    "max_expansions": 20,
+                    "fuzzy_transpositions": false,
+                    "fuzzy_rewrite": "top_terms_10",
+                    "lenient": true
+                }
+            }
+        })
+    );
+
+    // Any one of the options switches to the complex form
+    assert_eq!(
+        MatchQuery::new("title", "fox").prefix_length(1).to_json(),
+        serde_json::json!({ "match": { "title": { "query": "fox", "prefix_length": 1 } } })
+    );
+}
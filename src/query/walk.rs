@@ -0,0 +1,106 @@
+use crate::QueryType;
+
+impl<'a> QueryType<'a> {
+    /// Visit this query and every query nested inside it, parents before their children.
+    ///
+    /// Bool clauses are visited in must, must not, should, filter order, and a function score
+    /// query is visited before the filters of its functions.
+    pub fn walk<'s, F: FnMut(&'s QueryType<'a>)>(&'s self, f: &mut F) {
+        f(self);
+        match self {
+            QueryType::Bool(bool_query) => {
+                for (_, clause) in bool_query.clauses() {
+                    clause.walk(f);
+                }
+            }
+            QueryType::ConstantScore(constant_score) => constant_score.filter.walk(f),
+            QueryType::DisMax(dis_max) => {
+                for query in dis_max.queries.iter() {
+                    query.walk(f);
+                }
+            }
+            QueryType::FunctionScore(function_score) => {
+                if let Some(ref query) = function_score.query {
+                    query.walk(f);
+                }
+                for function in function_score.functions.iter() {
+                    if let Some(ref filter) = function.filter {
+                        filter.walk(f);
+                    }
+                }
+            }
+            QueryType::HasChild(has_child) => has_child.query.walk(f),
+            QueryType::HasParent(has_parent) => has_parent.query.walk(f),
+            QueryType::Nested(nested) => nested.query.walk(f),
+            QueryType::GeoDistance(_)
+            | QueryType::MatchAll(_)
+            | QueryType::MatchNone
+            | QueryType::MatchPhrase(_)
+            | QueryType::MatchPhrasePrefix(_)
+            | QueryType::Match(_)
+            | QueryType::MultiMatch(_)
+            | QueryType::QueryString(_)
+            | QueryType::Range(_)
+            | QueryType::Regexp(_)
+            | QueryType::Term(_)
+            | QueryType::Terms(_)
+            | QueryType::WildCard(_)
+            | QueryType::Raw(_) => {}
+        }
+    }
+
+    /// The `_name` set on the query, used to report which named queries matched a hit
+    pub fn query_name(&self) -> Option<&str> {
+        match self {
+            QueryType::Term(term) => term.name.as_deref(),
+            QueryType::Bool(_)
+            | QueryType::ConstantScore(_)
+            | QueryType::DisMax(_)
+            | QueryType::FunctionScore(_)
+            | QueryType::GeoDistance(_)
+            | QueryType::HasChild(_)
+            | QueryType::HasParent(_)
+            | QueryType::MatchAll(_)
+            | QueryType::MatchNone
+            | QueryType::MatchPhrase(_)
+            | QueryType::MatchPhrasePrefix(_)
+            | QueryType::Match(_)
+            | QueryType::MultiMatch(_)
+            | QueryType::Nested(_)
+            | QueryType::QueryString(_)
+            | QueryType::Range(_)
+            | QueryType::Regexp(_)
+            | QueryType::Terms(_)
+            | QueryType::WildCard(_)
+            | QueryType::Raw(_) => None,
+        }
+    }
+
+    /// The field a leaf query targets, `None` for compound queries and queries that search
+    /// several or no fields
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            QueryType::GeoDistance(geo_distance) => Some(&geo_distance.field),
+            QueryType::MatchPhrase(match_phrase) => Some(&match_phrase.field),
+            QueryType::MatchPhrasePrefix(match_phrase_prefix) => Some(&match_phrase_prefix.field),
+            QueryType::Match(match_query) => Some(&match_query.field),
+            QueryType::Range(range) => Some(&range.field),
+            QueryType::Regexp(regexp) => Some(&regexp.field),
+            QueryType::Term(term) => Some(&term.field),
+            QueryType::Terms(terms) => Some(&terms.field),
+            QueryType::WildCard(wildcard) => Some(wildcard.field()),
+            QueryType::Bool(_)
+            | QueryType::ConstantScore(_)
+            | QueryType::DisMax(_)
+            | QueryType::FunctionScore(_)
+            | QueryType::HasChild(_)
+            | QueryType::HasParent(_)
+            | QueryType::MatchAll(_)
+            | QueryType::MatchNone
+            | QueryType::MultiMatch(_)
+            | QueryType::Nested(_)
+            | QueryType::QueryString(_)
+            | QueryType::Raw(_) => None,
+        }
+    }
+}
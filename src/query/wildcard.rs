@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
+use crate::util::finite;
 use crate::{QueryType, ToOpenSearchJson};
 
 /// Wildcard Query
@@ -43,6 +44,24 @@ impl<'a> WildcardQuery<'a> {
         self
     }
 
+    /// The field to search
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub(crate) fn boost_value(&self) -> Option<f64> {
+        self.boost
+    }
+
+    pub(crate) fn field_mut(&mut self) -> &mut Cow<'a, str> {
+        &mut self.field
+    }
+
+    /// Whether the pattern starts with a wildcard, which forces a scan of every term
+    pub fn has_leading_wildcard(&self) -> bool {
+        self.value.starts_with(['*', '?'])
+    }
+
     /// Convert to an owned version with 'static lifetime
     pub fn to_owned(&self) -> WildcardQuery<'static> {
         WildcardQuery {
@@ -68,7 +87,7 @@ impl<'a> ToOpenSearchJson for WildcardQuery<'a> {
         field_obj.insert("value".to_string(), Value::String(self.value.to_string()));
         field_obj.insert("case_insensitive".to_string(), self.case_insensitive.into());
 
-        if let Some(boost) = self.boost {
+        if let Some(boost) = self.boost.and_then(finite) {
             field_obj.insert("boost".to_string(), boost.into());
         }
 
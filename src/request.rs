@@ -1,21 +1,42 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::io::Write;
 
 use serde::Serialize;
 use serde_json::{Map, Value};
 
 use crate::util::is_empty_slice;
-use crate::{QueryType, ToOpenSearchJson};
+use crate::{BoolQuery, QueryType, ToOpenSearchJson};
 
 mod aggregation_type;
+mod cached_request;
 mod collapse;
+mod fields;
 mod highlight;
+mod map_fields;
+mod multi_search;
+mod pit;
+#[cfg(feature = "schema")]
+mod schema;
+mod search_template;
+mod slice;
 mod sort_type;
+mod suggest;
+mod validation;
 
 pub use aggregation_type::*;
+pub use cached_request::*;
 pub use collapse::*;
+pub use fields::*;
 pub use highlight::*;
+pub use multi_search::*;
+pub use pit::*;
+pub use search_template::*;
+pub use slice::*;
 pub use sort_type::*;
+pub use suggest::*;
+pub(crate) use validation::require_finite;
+pub use validation::*;
 
 /// Struct representing a search request.
 #[derive(Default, Debug, Clone, Serialize)]
@@ -39,6 +60,9 @@ pub struct SearchRequest<'a> {
     /// Source fields
     #[serde(skip_serializing_if = "is_empty_slice", default, borrow)]
     pub _source: Cow<'a, [Cow<'a, str>]>,
+    /// Fields to retrieve formatted values for, including runtime fields
+    #[serde(skip_serializing_if = "is_empty_slice", default, borrow)]
+    pub fields: Cow<'a, [FieldAndFormat<'a>]>,
     /// Highlight
     #[serde(skip_serializing_if = "Option::is_none")]
     pub highlight: Option<Highlight<'a>>,
@@ -51,6 +75,21 @@ pub struct SearchRequest<'a> {
     /// Search after (cursor-based pagination)
     #[serde(skip_serializing_if = "is_empty_slice", default, borrow)]
     pub search_after: Cow<'a, [Value]>,
+    /// Point in time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pit: Option<Pit<'a>>,
+    /// Slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slice: Option<Slice>,
+    /// Whether to return partial results when some shards fail or time out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_partial_search_results: Option<bool>,
+    /// Suggest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggest: Option<Suggest<'a>>,
+    /// Runtime field definitions for this request, emitted as-is
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_mappings: Option<Map<String, Value>>,
 }
 
 impl<'a> SearchRequest<'a> {
@@ -59,12 +98,56 @@ impl<'a> SearchRequest<'a> {
         Self::default()
     }
 
+    /// Create a SearchRequest that only runs the given aggregations.
+    ///
+    /// The size is 0 so no hits are fetched, and without a query OpenSearch aggregates over
+    /// every document.
+    pub fn aggregations_only(aggs: HashMap<Cow<'a, str>, AggregationType<'a>>) -> Self {
+        Self {
+            size: Some(0),
+            aggs,
+            ..Self::default()
+        }
+    }
+
     /// Set the query
     pub fn query(mut self, query: QueryType<'a>) -> Self {
         self.query = Some(query);
         self
     }
 
+    /// AND a filter onto the existing query.
+    ///
+    /// If the query is a bool query the filter is pushed to its `filter` clause, setting
+    /// `minimum_should_match` to 1 if it only had should clauses so they stay required. Otherwise
+    /// the existing query is wrapped in a new bool query as `must` alongside the filter. Without
+    /// a query, the query becomes a bool query containing only the filter.
+    pub fn add_filter(mut self, filter: QueryType<'a>) -> Self {
+        self.query = Some(and_filter(self.query.take(), filter));
+        self
+    }
+
+    /// Turn the request into one that only counts and aggregates.
+    ///
+    /// Sets the size to 0 and clears the sort, source fields and highlight, keeping the query
+    /// and aggregations.
+    pub fn count_only(mut self) -> Self {
+        self.size = Some(0);
+        self.sort = Cow::Borrowed(&[]);
+        self._source = Cow::Borrowed(&[]);
+        self.highlight = None;
+        self
+    }
+
+    /// Every `_name` set on the query or the queries nested inside it
+    pub fn named_queries(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        if let Some(ref query) = self.query {
+            query.walk(&mut |q| names.extend(q.query_name()));
+        }
+        names
+    }
+
     /// Set the maximum number of results to return
     pub fn size(mut self, size: u32) -> Self {
         self.size = Some(size);
@@ -77,6 +160,14 @@ impl<'a> SearchRequest<'a> {
         self
     }
 
+    /// Set the size and offset for a zero-based page, the offset saturates instead of
+    /// overflowing
+    pub fn paginate(mut self, page: u32, page_size: u32) -> Self {
+        self.size = Some(page_size);
+        self.from = Some(page.saturating_mul(page_size));
+        self
+    }
+
     /// Add a sort criterion
     pub fn sort(mut self, sort: SortType<'a>) -> Self {
         self.sort.to_mut().push(sort);
@@ -89,6 +180,23 @@ impl<'a> SearchRequest<'a> {
         self
     }
 
+    /// Add a facet counted over every document, ignoring the query.
+    ///
+    /// This adds a `global` aggregation named `name` with a `terms` sub-aggregation on `field`,
+    /// also named `name`, returning up to `size` buckets, so unscoped counts come back alongside
+    /// the query's results.
+    pub fn add_global_facet(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        field: impl Into<Cow<'a, str>>,
+        size: u32,
+    ) -> Self {
+        let name = name.into();
+        self.aggs
+            .insert(name.clone(), global_facet(name, field, size));
+        self
+    }
+
     /// Set source fields
     pub fn source_fields<I>(mut self, fields: I) -> Self
     where
@@ -99,6 +207,16 @@ impl<'a> SearchRequest<'a> {
         self
     }
 
+    /// Set the fields to retrieve formatted values for
+    pub fn fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<FieldAndFormat<'a>>,
+    {
+        self.fields = fields.into_iter().map(|f| f.into()).collect();
+        self
+    }
+
     /// Set the highlight configuration
     pub fn highlight(mut self, highlight: Highlight<'a>) -> Self {
         self.highlight = Some(highlight);
@@ -125,6 +243,47 @@ impl<'a> SearchRequest<'a> {
         self.search_after = values.into();
         self
     }
+
+    /// Set the point in time
+    pub fn pit(mut self, pit: Pit<'a>) -> Self {
+        self.pit = Some(pit);
+        self
+    }
+
+    /// Set the slice
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Set whether to return partial results when some shards fail or time out
+    pub fn allow_partial_search_results(mut self, allow: bool) -> Self {
+        self.allow_partial_search_results = Some(allow);
+        self
+    }
+
+    /// Set the suggesters
+    pub fn suggest(mut self, suggest: Suggest<'a>) -> Self {
+        self.suggest = Some(suggest);
+        self
+    }
+
+    /// Set the runtime field definitions, keyed by field name
+    pub fn runtime_mappings(mut self, runtime_mappings: Map<String, Value>) -> Self {
+        self.runtime_mappings = Some(runtime_mappings);
+        self
+    }
+
+    /// Serialize the request body to JSON bytes, ready to send as an HTTP body
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.to_json()).expect("a JSON value always serializes")
+    }
+
+    /// Serialize the request body as JSON into `writer`
+    pub fn to_json_writer<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        serde_json::to_writer(writer, &self.to_json())?;
+        Ok(())
+    }
 }
 
 impl<'a> ToOpenSearchJson for SearchRequest<'a> {
@@ -165,6 +324,11 @@ impl<'a> ToOpenSearchJson for SearchRequest<'a> {
             result.insert("_source".to_string(), Value::Array(sources));
         }
 
+        if !self.fields.is_empty() {
+            let fields: Vec<Value> = self.fields.iter().map(|f| f.to_json()).collect();
+            result.insert("fields".to_string(), Value::Array(fields));
+        }
+
         if let Some(ref highlight) = self.highlight {
             result.insert("highlight".to_string(), highlight.to_json());
         }
@@ -187,6 +351,32 @@ impl<'a> ToOpenSearchJson for SearchRequest<'a> {
             );
         }
 
+        if let Some(ref pit) = self.pit {
+            result.insert("pit".to_string(), pit.to_json());
+        }
+
+        if let Some(ref slice) = self.slice {
+            result.insert("slice".to_string(), slice.to_json());
+        }
+
+        if let Some(allow_partial_search_results) = self.allow_partial_search_results {
+            result.insert(
+                "allow_partial_search_results".to_string(),
+                Value::Bool(allow_partial_search_results),
+            );
+        }
+
+        if let Some(ref suggest) = self.suggest {
+            result.insert("suggest".to_string(), suggest.to_json());
+        }
+
+        if let Some(ref runtime_mappings) = self.runtime_mappings {
+            result.insert(
+                "runtime_mappings".to_string(),
+                Value::Object(runtime_mappings.clone()),
+            );
+        }
+
         Value::Object(result)
     }
 }
@@ -202,10 +392,16 @@ pub struct SearchRequestBuilder<'a> {
     sort: Cow<'a, [SortType<'a>]>,
     aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
     _source: Cow<'a, [Cow<'a, str>]>,
+    fields: Cow<'a, [FieldAndFormat<'a>]>,
     highlight: Option<Highlight<'a>>,
     track_total_hits: Option<bool>,
     collapse: Option<Collapse<'a>>,
     search_after: Cow<'a, [Value]>,
+    pit: Option<Pit<'a>>,
+    slice: Option<Slice>,
+    allow_partial_search_results: Option<bool>,
+    suggest: Option<Suggest<'a>>,
+    runtime_mappings: Option<Map<String, Value>>,
 }
 
 impl<'a> SearchRequestBuilder<'a> {
@@ -214,12 +410,48 @@ impl<'a> SearchRequestBuilder<'a> {
         Self::default()
     }
 
+    /// Create a SearchRequestBuilder seeded with the fields of an existing request.
+    /// Useful for deriving variants from a common base request.
+    pub fn from_request(request: &SearchRequest<'a>) -> Self {
+        let request = request.clone();
+        Self {
+            query: request.query,
+            size: request.size,
+            from: request.from,
+            sort: request.sort,
+            aggs: request.aggs,
+            _source: request._source,
+            fields: request.fields,
+            highlight: request.highlight,
+            track_total_hits: request.track_total_hits,
+            collapse: request.collapse,
+            search_after: request.search_after,
+            pit: request.pit,
+            slice: request.slice,
+            allow_partial_search_results: request.allow_partial_search_results,
+            suggest: request.suggest,
+            runtime_mappings: request.runtime_mappings,
+        }
+    }
+
+    /// Clear all fields, leaving an empty builder
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::default();
+        self
+    }
+
     /// Set the query for this search request
     pub fn query(&mut self, query: QueryType<'a>) -> &mut Self {
         self.query = Some(query);
         self
     }
 
+    /// AND a filter onto the existing query, see [`SearchRequest::add_filter`]
+    pub fn add_filter(&mut self, filter: QueryType<'a>) -> &mut Self {
+        self.query = Some(and_filter(self.query.take(), filter));
+        self
+    }
+
     /// Set the maximum number of results to return
     pub fn size(&mut self, size: u32) -> &mut Self {
         self.size = Some(size);
@@ -232,12 +464,35 @@ impl<'a> SearchRequestBuilder<'a> {
         self
     }
 
+    /// Set the size and offset for a zero-based page, the offset saturates instead of
+    /// overflowing
+    pub fn paginate(&mut self, page: u32, page_size: u32) -> &mut Self {
+        self.size = Some(page_size);
+        self.from = Some(page.saturating_mul(page_size));
+        self
+    }
+
     /// Add a sort criterion (can be called multiple times)
     pub fn add_sort(&mut self, sort: SortType<'a>) -> &mut Self {
         self.sort.to_mut().push(sort);
         self
     }
 
+    /// Remove sort criteria identical to an earlier one, keeping the first occurrence
+    pub fn dedup_sorts(&mut self) -> &mut Self {
+        let mut seen = Vec::new();
+        self.sort.to_mut().retain(|sort| {
+            let json = sort.to_json();
+            if seen.contains(&json) {
+                false
+            } else {
+                seen.push(json);
+                true
+            }
+        });
+        self
+    }
+
     /// Set all sort criteria at once (replaces existing sorts)
     pub fn set_sorts(&mut self, sorts: Cow<'a, [SortType<'a>]>) -> &mut Self {
         self.sort = sorts;
@@ -260,6 +515,19 @@ impl<'a> SearchRequestBuilder<'a> {
         self
     }
 
+    /// Add a facet counted over every document, see [`SearchRequest::add_global_facet`]
+    pub fn add_global_facet(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        field: impl Into<Cow<'a, str>>,
+        size: u32,
+    ) -> &mut Self {
+        let name = name.into();
+        self.aggs
+            .insert(name.clone(), global_facet(name, field, size));
+        self
+    }
+
     /// Remove an aggregation by name
     pub fn remove_agg(&mut self, name: impl Into<Cow<'a, str>>) -> &mut Self {
         self.aggs.remove(&name.into());
@@ -278,6 +546,15 @@ impl<'a> SearchRequestBuilder<'a> {
         self
     }
 
+    /// Add a source field to include in the response, unless it's already included
+    pub fn add_source_field_unique(&mut self, field: impl Into<Cow<'a, str>>) -> &mut Self {
+        let field = field.into();
+        if !self._source.contains(&field) {
+            self._source.to_mut().push(field);
+        }
+        self
+    }
+
     /// Set source fields (replaces existing fields)
     pub fn set_source_fields<I>(&mut self, fields: I) -> &mut Self
     where
@@ -294,6 +571,28 @@ impl<'a> SearchRequestBuilder<'a> {
         self
     }
 
+    /// Add a field to retrieve formatted values for
+    pub fn add_field(&mut self, field: impl Into<FieldAndFormat<'a>>) -> &mut Self {
+        self.fields.to_mut().push(field.into());
+        self
+    }
+
+    /// Set the fields to retrieve formatted values for (replaces existing fields)
+    pub fn set_fields<I>(&mut self, fields: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<FieldAndFormat<'a>>,
+    {
+        self.fields = fields.into_iter().map(|f| f.into()).collect();
+        self
+    }
+
+    /// Clear all fields to retrieve formatted values for
+    pub fn clear_fields(&mut self) -> &mut Self {
+        self.fields = Cow::Borrowed(&[]);
+        self
+    }
+
     /// Set the highlight configuration
     pub fn highlight(&mut self, highlight: Highlight<'a>) -> &mut Self {
         self.highlight = Some(highlight);
@@ -333,6 +632,36 @@ impl<'a> SearchRequestBuilder<'a> {
         self
     }
 
+    /// Set the point in time
+    pub fn pit(&mut self, pit: Pit<'a>) -> &mut Self {
+        self.pit = Some(pit);
+        self
+    }
+
+    /// Set the slice
+    pub fn slice(&mut self, slice: Slice) -> &mut Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Set whether to return partial results when some shards fail or time out
+    pub fn allow_partial_search_results(&mut self, allow: bool) -> &mut Self {
+        self.allow_partial_search_results = Some(allow);
+        self
+    }
+
+    /// Set the suggesters
+    pub fn suggest(&mut self, suggest: Suggest<'a>) -> &mut Self {
+        self.suggest = Some(suggest);
+        self
+    }
+
+    /// Set the runtime field definitions, keyed by field name
+    pub fn runtime_mappings(&mut self, runtime_mappings: Map<String, Value>) -> &mut Self {
+        self.runtime_mappings = Some(runtime_mappings);
+        self
+    }
+
     /// Build the final SearchRequest
     pub fn build(self) -> SearchRequest<'a> {
         SearchRequest {
@@ -342,10 +671,49 @@ impl<'a> SearchRequestBuilder<'a> {
             sort: self.sort,
             aggs: self.aggs,
             _source: self._source,
+            fields: self.fields,
             highlight: self.highlight,
             track_total_hits: self.track_total_hits,
             collapse: self.collapse,
             search_after: self.search_after,
+            pit: self.pit,
+            slice: self.slice,
+            allow_partial_search_results: self.allow_partial_search_results,
+            suggest: self.suggest,
+            runtime_mappings: self.runtime_mappings,
         }
     }
 }
+
+/// Push `filter` onto a bool `query`, or wrap any other query in a bool query alongside it
+fn and_filter<'a>(query: Option<QueryType<'a>>, filter: QueryType<'a>) -> QueryType<'a> {
+    match query {
+        Some(QueryType::Bool(mut bool_query)) => {
+            // OpenSearch only requires one should clause to match when there are no must or
+            // filter clauses, so pin that down before the filter makes them optional
+            if !bool_query.should.is_empty()
+                && bool_query.must.is_empty()
+                && bool_query.filter.is_empty()
+                && bool_query.minimum_should_match.is_none()
+            {
+                bool_query = bool_query.minimum_should_match(1);
+            }
+            QueryType::Bool(bool_query.filter(filter))
+        }
+        Some(query) => QueryType::Bool(BoolQuery::new().must(query).filter(filter)),
+        None => QueryType::Bool(BoolQuery::new().filter(filter)),
+    }
+}
+
+/// A `global` aggregation with a `terms` sub-aggregation on `field` named `name`
+fn global_facet<'a>(
+    name: Cow<'a, str>,
+    field: impl Into<Cow<'a, str>>,
+    size: u32,
+) -> AggregationType<'a> {
+    let terms = AggregationType::Terms(TermsAggregation::new(field).size(size));
+    AggregationType::Global(GlobalAggregation::new().sub_agg(name, terms))
+}
+
+#[cfg(test)]
+mod test;
@@ -4,7 +4,53 @@ use std::collections::HashMap;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
-use crate::ToOpenSearchJson;
+use crate::{SortOrder, SortType, ToOpenSearchJson};
+
+mod adjacency_matrix;
+mod auto_date_histogram;
+mod bucket_selector;
+mod bucket_sort;
+mod buckets_path;
+mod cumulative_sum;
+mod date_histogram;
+mod derivative;
+mod filters;
+mod geo_centroid;
+mod geo_distance;
+mod geohash_grid;
+mod global;
+mod moving_fn;
+mod percentile_ranks;
+mod range_bucket;
+mod sampler;
+mod significant_terms;
+mod sum;
+mod top_hits;
+mod top_metrics;
+mod value_count;
+
+pub use adjacency_matrix::*;
+pub use auto_date_histogram::*;
+pub use bucket_selector::*;
+pub use bucket_sort::*;
+pub use buckets_path::*;
+pub use cumulative_sum::*;
+pub use date_histogram::*;
+pub use derivative::*;
+pub use filters::*;
+pub use geo_centroid::*;
+pub use geo_distance::*;
+pub use geohash_grid::*;
+pub use global::*;
+pub use moving_fn::*;
+pub use percentile_ranks::*;
+pub use range_bucket::*;
+pub use sampler::*;
+pub use significant_terms::*;
+pub use sum::*;
+pub use top_hits::*;
+pub use top_metrics::*;
+pub use value_count::*;
 
 /// Cardinality Aggregation
 #[derive(Debug, Clone, Serialize)]
@@ -12,6 +58,9 @@ pub struct CardinalityAggregation<'a> {
     /// The field to aggregate
     #[serde(borrow)]
     pub field: Cow<'a, str>,
+    /// Metadata returned untouched alongside the aggregation result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Map<String, Value>>,
 }
 
 impl<'a> CardinalityAggregation<'a> {
@@ -19,8 +68,15 @@ impl<'a> CardinalityAggregation<'a> {
     pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
         Self {
             field: field.into(),
+            meta: None,
         }
     }
+
+    /// Set the metadata for this aggregation
+    pub fn meta(mut self, meta: Map<String, Value>) -> Self {
+        self.meta = Some(meta);
+        self
+    }
 }
 
 impl<'a> ToOpenSearchJson for CardinalityAggregation<'a> {
@@ -29,6 +85,11 @@ impl<'a> ToOpenSearchJson for CardinalityAggregation<'a> {
         let mut cardinality_obj = Map::new();
         cardinality_obj.insert("field".to_string(), Value::String(self.field.to_string()));
         result.insert("cardinality".to_string(), Value::Object(cardinality_obj));
+
+        if let Some(ref meta) = self.meta {
+            result.insert("meta".to_string(), Value::Object(meta.clone()));
+        }
+
         Value::Object(result)
     }
 }
@@ -42,9 +103,23 @@ pub struct TermsAggregation<'a> {
     /// The maximum number of terms to return
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<u32>,
+    /// The value used for documents missing the field, they are bucketed under it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing: Option<Value>,
+    /// The key the buckets are ordered by, e.g. `_count` or `_key`, and its direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub order: Option<(Cow<'a, str>, SortOrder)>,
+    /// The format of the bucket key strings, a date pattern such as `yyyy-MM` for date fields or
+    /// a decimal pattern such as `0.00` for numeric fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<Cow<'a, str>>,
     /// Sub-aggregations
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+    /// Metadata returned untouched alongside the aggregation result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Map<String, Value>>,
 }
 
 impl<'a> TermsAggregation<'a> {
@@ -53,7 +128,11 @@ impl<'a> TermsAggregation<'a> {
         Self {
             field: field.into(),
             size: None,
+            missing: None,
+            order: None,
+            format: None,
             sub_aggs: HashMap::new(),
+            meta: None,
         }
     }
 
@@ -63,11 +142,35 @@ impl<'a> TermsAggregation<'a> {
         self
     }
 
+    /// Set the value used for documents missing the field
+    pub fn missing<T: Into<Value>>(mut self, missing: T) -> Self {
+        self.missing = Some(missing.into());
+        self
+    }
+
+    /// Set the key the buckets are ordered by, e.g. `_count` or `_key`
+    pub fn order(mut self, key: impl Into<Cow<'a, str>>, order: SortOrder) -> Self {
+        self.order = Some((key.into(), order));
+        self
+    }
+
+    /// Set the format of the bucket key strings, e.g. `yyyy-MM` for a date field
+    pub fn format(mut self, format: impl Into<Cow<'a, str>>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
     /// Add a sub-aggregation
     pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
         self.sub_aggs.insert(name.into(), agg);
         self
     }
+
+    /// Set the metadata for this aggregation
+    pub fn meta(mut self, meta: Map<String, Value>) -> Self {
+        self.meta = Some(meta);
+        self
+    }
 }
 
 impl<'a> ToOpenSearchJson for TermsAggregation<'a> {
@@ -79,6 +182,20 @@ impl<'a> ToOpenSearchJson for TermsAggregation<'a> {
             terms_obj.insert("size".to_string(), Value::Number(size.into()));
         }
 
+        if let Some(ref missing) = self.missing {
+            terms_obj.insert("missing".to_string(), missing.clone());
+        }
+
+        if let Some((ref key, ref order)) = self.order {
+            let mut order_obj = Map::new();
+            order_obj.insert(key.to_string(), Value::String(order.as_str().to_string()));
+            terms_obj.insert("order".to_string(), Value::Object(order_obj));
+        }
+
+        if let Some(ref format) = self.format {
+            terms_obj.insert("format".to_string(), Value::String(format.to_string()));
+        }
+
         let mut result = Map::new();
         result.insert("terms".to_string(), Value::Object(terms_obj));
 
@@ -90,18 +207,211 @@ impl<'a> ToOpenSearchJson for TermsAggregation<'a> {
             result.insert("aggs".to_string(), Value::Object(aggs_obj));
         }
 
+        if let Some(ref meta) = self.meta {
+            result.insert("meta".to_string(), Value::Object(meta.clone()));
+        }
+
         Value::Object(result)
     }
 }
 
+/// Builder pattern for TermsAggregation that allows dynamic updates.
+#[derive(Debug, Clone)]
+pub struct TermsAggregationBuilder<'a> {
+    field: Cow<'a, str>,
+    size: Option<u32>,
+    missing: Option<Value>,
+    order: Option<(Cow<'a, str>, SortOrder)>,
+    format: Option<Cow<'a, str>>,
+    sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+    meta: Option<Map<String, Value>>,
+}
+
+impl<'a> TermsAggregationBuilder<'a> {
+    /// Create a new TermsAggregationBuilder for the given field
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            size: None,
+            missing: None,
+            order: None,
+            format: None,
+            sub_aggs: HashMap::new(),
+            meta: None,
+        }
+    }
+
+    /// Set the maximum number of terms to return
+    pub fn size(&mut self, size: u32) -> &mut Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the value used for documents missing the field
+    pub fn missing<T: Into<Value>>(&mut self, missing: T) -> &mut Self {
+        self.missing = Some(missing.into());
+        self
+    }
+
+    /// Set the key the buckets are ordered by, e.g. `_count` or `_key`
+    pub fn order(&mut self, key: impl Into<Cow<'a, str>>, order: SortOrder) -> &mut Self {
+        self.order = Some((key.into(), order));
+        self
+    }
+
+    /// Set the format of the bucket key strings (replaces existing format)
+    pub fn format(&mut self, format: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        agg: AggregationType<'a>,
+    ) -> &mut Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+
+    /// Set the metadata for this aggregation
+    pub fn meta(&mut self, meta: Map<String, Value>) -> &mut Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    /// Build the final TermsAggregation
+    pub fn build(self) -> TermsAggregation<'a> {
+        TermsAggregation {
+            field: self.field,
+            size: self.size,
+            missing: self.missing,
+            order: self.order,
+            format: self.format,
+            sub_aggs: self.sub_aggs,
+            meta: self.meta,
+        }
+    }
+}
+
 /// Aggregation Type
-#[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type", content = "params")]
+#[derive(Debug, Clone)]
 pub enum AggregationType<'a> {
     /// Terms aggregation
     Terms(TermsAggregation<'a>),
     /// Cardinality aggregation
     Cardinality(CardinalityAggregation<'a>),
+    /// Value count aggregation
+    ValueCount(ValueCountAggregation<'a>),
+    /// Percentile ranks aggregation
+    PercentileRanks(PercentileRanksAggregation<'a>),
+    /// Top metrics aggregation
+    TopMetrics(TopMetricsAggregation<'a>),
+    /// Adjacency matrix aggregation
+    AdjacencyMatrix(AdjacencyMatrixAggregation<'a>),
+    /// Significant terms aggregation
+    SignificantTerms(SignificantTermsAggregation<'a>),
+    /// Sampler aggregation
+    Sampler(SamplerAggregation<'a>),
+    /// Top hits aggregation
+    TopHits(TopHitsAggregation<'a>),
+    /// Global aggregation
+    Global(GlobalAggregation<'a>),
+    /// Geo distance aggregation
+    GeoDistance(GeoDistanceAggregation<'a>),
+    /// Geohash grid aggregation
+    GeohashGrid(GeohashGridAggregation<'a>),
+    /// Geo centroid aggregation
+    GeoCentroid(GeoCentroidAggregation<'a>),
+    /// Auto date histogram aggregation
+    AutoDateHistogram(AutoDateHistogramAggregation<'a>),
+    /// Sum aggregation
+    Sum(SumAggregation<'a>),
+    /// Date histogram aggregation
+    DateHistogram(DateHistogramAggregation<'a>),
+    /// Cumulative sum pipeline aggregation
+    CumulativeSum(CumulativeSumAggregation<'a>),
+    /// Derivative pipeline aggregation
+    Derivative(DerivativeAggregation<'a>),
+    /// Moving function pipeline aggregation
+    MovingFn(MovingFnAggregation<'a>),
+    /// Bucket selector pipeline aggregation
+    BucketSelector(BucketSelectorAggregation<'a>),
+    /// Bucket sort pipeline aggregation
+    BucketSort(BucketSortAggregation<'a>),
+    /// Filters aggregation
+    Filters(FiltersAggregation<'a>),
+}
+
+impl<'a> AggregationType<'a> {
+    /// Group documents by the values of a field, returning up to `top_n_per_group` example hits
+    /// for each group.
+    ///
+    /// This builds a `terms` aggregation with a nested `top_hits` sub-aggregation named
+    /// `top_hits`, an alternative to field collapsing.
+    pub fn group_by(field: impl Into<Cow<'a, str>>, top_n_per_group: u32) -> Self {
+        AggregationType::Terms(TermsAggregation::new(field).sub_agg(
+            "top_hits",
+            AggregationType::TopHits(TopHitsAggregation::new().size(top_n_per_group)),
+        ))
+    }
+
+    /// The sub-aggregations of a bucket aggregation, `None` for aggregations that can't have any
+    pub(crate) fn sub_aggs(&self) -> Option<&HashMap<Cow<'a, str>, AggregationType<'a>>> {
+        match self {
+            AggregationType::Terms(terms) => Some(&terms.sub_aggs),
+            AggregationType::AdjacencyMatrix(adjacency_matrix) => Some(&adjacency_matrix.sub_aggs),
+            AggregationType::SignificantTerms(significant_terms) => {
+                Some(&significant_terms.sub_aggs)
+            }
+            AggregationType::Sampler(sampler) => Some(&sampler.sub_aggs),
+            AggregationType::Global(global) => Some(&global.sub_aggs),
+            AggregationType::GeoDistance(geo_distance) => Some(&geo_distance.sub_aggs),
+            AggregationType::GeohashGrid(geohash_grid) => Some(&geohash_grid.sub_aggs),
+            AggregationType::AutoDateHistogram(auto_date_histogram) => {
+                Some(&auto_date_histogram.sub_aggs)
+            }
+            AggregationType::DateHistogram(date_histogram) => Some(&date_histogram.sub_aggs),
+            AggregationType::Filters(filters) => Some(&filters.sub_aggs),
+            AggregationType::Cardinality(_)
+            | AggregationType::ValueCount(_)
+            | AggregationType::PercentileRanks(_)
+            | AggregationType::TopMetrics(_)
+            | AggregationType::TopHits(_)
+            | AggregationType::GeoCentroid(_)
+            | AggregationType::Sum(_)
+            | AggregationType::CumulativeSum(_)
+            | AggregationType::Derivative(_)
+            | AggregationType::MovingFn(_)
+            | AggregationType::BucketSelector(_)
+            | AggregationType::BucketSort(_) => None,
+        }
+    }
+
+    /// The `buckets_path`s a pipeline aggregation reads, empty for other aggregations
+    pub(crate) fn buckets_paths(&self) -> Vec<&str> {
+        match self {
+            AggregationType::CumulativeSum(cumulative_sum) => vec![&cumulative_sum.buckets_path],
+            AggregationType::Derivative(derivative) => vec![&derivative.buckets_path],
+            AggregationType::MovingFn(moving_fn) => vec![&moving_fn.buckets_path],
+            AggregationType::BucketSelector(bucket_selector) => bucket_selector
+                .buckets_path
+                .values()
+                .map(|path| path.as_ref())
+                .collect(),
+            // Bucket sort sorts on paths to sibling aggregations rather than fields
+            AggregationType::BucketSort(bucket_sort) => bucket_sort
+                .sort
+                .iter()
+                .filter_map(|sort| match sort {
+                    SortType::Field(field_sort) => Some(field_sort.field.as_ref()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl<'a> ToOpenSearchJson for AggregationType<'a> {
@@ -109,6 +419,38 @@ impl<'a> ToOpenSearchJson for AggregationType<'a> {
         match self {
             AggregationType::Terms(terms) => terms.to_json(),
             AggregationType::Cardinality(cardinality) => cardinality.to_json(),
+            AggregationType::ValueCount(value_count) => value_count.to_json(),
+            AggregationType::PercentileRanks(percentile_ranks) => percentile_ranks.to_json(),
+            AggregationType::TopMetrics(top_metrics) => top_metrics.to_json(),
+            AggregationType::AdjacencyMatrix(adjacency_matrix) => adjacency_matrix.to_json(),
+            AggregationType::SignificantTerms(significant_terms) => significant_terms.to_json(),
+            AggregationType::Sampler(sampler) => sampler.to_json(),
+            AggregationType::Global(global) => global.to_json(),
+            AggregationType::GeoDistance(geo_distance) => geo_distance.to_json(),
+            AggregationType::GeohashGrid(geohash_grid) => geohash_grid.to_json(),
+            AggregationType::GeoCentroid(geo_centroid) => geo_centroid.to_json(),
+            AggregationType::AutoDateHistogram(auto_date_histogram) => {
+                auto_date_histogram.to_json()
+            }
+            AggregationType::Sum(sum) => sum.to_json(),
+            AggregationType::DateHistogram(date_histogram) => date_histogram.to_json(),
+            AggregationType::CumulativeSum(cumulative_sum) => cumulative_sum.to_json(),
+            AggregationType::Derivative(derivative) => derivative.to_json(),
+            AggregationType::MovingFn(moving_fn) => moving_fn.to_json(),
+            AggregationType::BucketSelector(bucket_selector) => bucket_selector.to_json(),
+            AggregationType::BucketSort(bucket_sort) => bucket_sort.to_json(),
+            AggregationType::Filters(filters) => filters.to_json(),
+            AggregationType::TopHits(top_hits) => top_hits.to_json(),
         }
     }
 }
+
+/// Serializes to the same OpenSearch JSON as [`ToOpenSearchJson::to_json`].
+impl<'a> Serialize for AggregationType<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test;
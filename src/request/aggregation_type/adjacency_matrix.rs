@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, QueryType, ToOpenSearchJson};
+
+/// Adjacency Matrix Aggregation
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AdjacencyMatrixAggregation<'a> {
+    /// The named filters to build the matrix from
+    ///
+    /// Stored in a sorted map so the generated buckets are always emitted in the same order.
+    #[serde(borrow)]
+    pub filters: BTreeMap<Cow<'a, str>, QueryType<'a>>,
+    /// The separator used to join the names of intersecting filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separator: Option<Cow<'a, str>>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> AdjacencyMatrixAggregation<'a> {
+    /// Create a new empty AdjacencyMatrixAggregation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a named filter
+    pub fn filter(mut self, name: impl Into<Cow<'a, str>>, query: QueryType<'a>) -> Self {
+        self.filters.insert(name.into(), query);
+        self
+    }
+
+    /// Set the separator used to join the names of intersecting filters
+    pub fn separator(mut self, separator: impl Into<Cow<'a, str>>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for AdjacencyMatrixAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut filters_obj = Map::new();
+        for (name, query) in &self.filters {
+            filters_obj.insert(name.to_string(), query.to_json());
+        }
+
+        let mut adjacency_matrix_obj = Map::new();
+        adjacency_matrix_obj.insert("filters".to_string(), Value::Object(filters_obj));
+
+        if let Some(ref separator) = self.separator {
+            adjacency_matrix_obj.insert(
+                "separator".to_string(),
+                Value::String(separator.to_string()),
+            );
+        }
+
+        let mut result = Map::new();
+        result.insert(
+            "adjacency_matrix".to_string(),
+            Value::Object(adjacency_matrix_obj),
+        );
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, DateFormat, ToOpenSearchJson};
+
+/// Auto Date Histogram Aggregation
+///
+/// A date histogram that picks the interval itself so at most `buckets` buckets are returned.
+#[derive(Debug, Clone, Serialize)]
+pub struct AutoDateHistogramAggregation<'a> {
+    /// The date field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The target number of buckets
+    pub buckets: u32,
+    /// The date format of the bucket keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<DateFormat<'a>>,
+    /// The time zone buckets are computed in, e.g. `+01:00` or `Europe/Amsterdam`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Cow<'a, str>>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> AutoDateHistogramAggregation<'a> {
+    /// Create a new AutoDateHistogramAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>, buckets: u32) -> Self {
+        Self {
+            field: field.into(),
+            buckets,
+            format: None,
+            time_zone: None,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Set the date format of the bucket keys
+    pub fn format(mut self, format: impl Into<DateFormat<'a>>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the time zone buckets are computed in
+    pub fn time_zone(mut self, time_zone: impl Into<Cow<'a, str>>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for AutoDateHistogramAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut histogram_obj = Map::new();
+        histogram_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        histogram_obj.insert("buckets".to_string(), Value::Number(self.buckets.into()));
+
+        if let Some(ref format) = self.format {
+            histogram_obj.insert("format".to_string(), Value::String(format.to_string()));
+        }
+        if let Some(ref time_zone) = self.time_zone {
+            histogram_obj.insert(
+                "time_zone".to_string(),
+                Value::String(time_zone.to_string()),
+            );
+        }
+
+        let mut result = Map::new();
+        result.insert(
+            "auto_date_histogram".to_string(),
+            Value::Object(histogram_obj),
+        );
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Bucket Selector Aggregation
+///
+/// A pipeline aggregation keeping only the buckets of its parent for which the script returns
+/// true. The script reads the metrics named in `buckets_path` as `params.<name>`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BucketSelectorAggregation<'a> {
+    /// Script variable names mapped to the paths of the metrics they hold, see
+    /// [`BucketsPath`](crate::BucketsPath)
+    #[serde(borrow)]
+    pub buckets_path: HashMap<Cow<'a, str>, Cow<'a, str>>,
+    /// The condition a bucket has to meet to be kept
+    #[serde(borrow)]
+    pub script: Cow<'a, str>,
+}
+
+impl<'a> BucketSelectorAggregation<'a> {
+    /// Create a new BucketSelectorAggregation without any script variables
+    pub fn new(script: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            buckets_path: HashMap::new(),
+            script: script.into(),
+        }
+    }
+
+    /// Expose the metric at `path` to the script as `params.<name>`
+    pub fn buckets_path(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        path: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.buckets_path.insert(name.into(), path.into());
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for BucketSelectorAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut buckets_path_obj = Map::new();
+        for (name, path) in &self.buckets_path {
+            buckets_path_obj.insert(name.to_string(), Value::String(path.to_string()));
+        }
+
+        let mut bucket_selector_obj = Map::new();
+        bucket_selector_obj.insert("buckets_path".to_string(), Value::Object(buckets_path_obj));
+        bucket_selector_obj.insert("script".to_string(), Value::String(self.script.to_string()));
+
+        let mut result = Map::new();
+        result.insert(
+            "bucket_selector".to_string(),
+            Value::Object(bucket_selector_obj),
+        );
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,105 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{SortType, ToOpenSearchJson};
+
+/// How a pipeline aggregation treats buckets missing the metric it reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapPolicy {
+    /// Skip buckets without the metric, the default
+    Skip,
+    /// Treat the missing metric as zero
+    InsertZeros,
+    /// Like skip, but keep non-null values the aggregation would otherwise drop
+    KeepValues,
+}
+
+impl GapPolicy {
+    /// The policy name as sent to OpenSearch
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GapPolicy::Skip => "skip",
+            GapPolicy::InsertZeros => "insert_zeros",
+            GapPolicy::KeepValues => "keep_values",
+        }
+    }
+}
+
+/// Bucket Sort Aggregation
+///
+/// A pipeline aggregation sorting and paginating the buckets of its parent. Without a sort it
+/// only truncates the buckets to `from` and `size`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BucketSortAggregation<'a> {
+    /// The sort criteria, referencing `_key`, `_count` or sibling metrics
+    #[serde(skip_serializing_if = "Vec::is_empty", default, borrow)]
+    pub sort: Vec<SortType<'a>>,
+    /// The number of buckets to skip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<u32>,
+    /// The number of buckets to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    /// How buckets missing a sort metric are treated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap_policy: Option<GapPolicy>,
+}
+
+impl<'a> BucketSortAggregation<'a> {
+    /// Create a new BucketSortAggregation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sort criterion (can be called multiple times)
+    pub fn sort(mut self, sort: SortType<'a>) -> Self {
+        self.sort.push(sort);
+        self
+    }
+
+    /// Set the number of buckets to skip
+    pub fn from(mut self, from: u32) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Set the number of buckets to return
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set how buckets missing a sort metric are treated
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for BucketSortAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut bucket_sort_obj = Map::new();
+
+        if !self.sort.is_empty() {
+            let sorts: Vec<Value> = self.sort.iter().map(|s| s.to_json()).collect();
+            bucket_sort_obj.insert("sort".to_string(), Value::Array(sorts));
+        }
+        if let Some(from) = self.from {
+            bucket_sort_obj.insert("from".to_string(), Value::Number(from.into()));
+        }
+        if let Some(size) = self.size {
+            bucket_sort_obj.insert("size".to_string(), Value::Number(size.into()));
+        }
+        if let Some(gap_policy) = self.gap_policy {
+            bucket_sort_obj.insert(
+                "gap_policy".to_string(),
+                Value::String(gap_policy.as_str().to_string()),
+            );
+        }
+
+        let mut result = Map::new();
+        result.insert("bucket_sort".to_string(), Value::Object(bucket_sort_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// A `buckets_path` referencing sibling aggregations, for use in pipeline aggregations.
+///
+/// Paths are built from aggregation names rather than written by hand, e.g.
+/// `BucketsPath::bucket("sales_per_month").then_metric("sales", Some("value"))` is
+/// `"sales_per_month>sales.value"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketsPath {
+    path: String,
+}
+
+impl BucketsPath {
+    /// A path to a metric aggregation. `metric` names the value of a multi-value metric, e.g.
+    /// `avg` of a stats aggregation, and is left out for single-value metrics.
+    pub fn metric(agg: &str, metric: Option<&str>) -> Self {
+        let mut path = BucketsPath {
+            path: String::new(),
+        };
+        path.push_metric(agg, metric);
+        path
+    }
+
+    /// A path starting at a multi-bucket aggregation
+    pub fn bucket(agg: &str) -> Self {
+        BucketsPath {
+            path: agg.to_string(),
+        }
+    }
+
+    /// The document count of the current bucket
+    pub fn count() -> Self {
+        BucketsPath {
+            path: "_count".to_string(),
+        }
+    }
+
+    /// Descend into a multi-bucket aggregation inside the current path
+    pub fn then_bucket(mut self, agg: &str) -> Self {
+        self.path.push('>');
+        self.path.push_str(agg);
+        self
+    }
+
+    /// End the path at a metric aggregation inside the current path
+    pub fn then_metric(mut self, agg: &str, metric: Option<&str>) -> Self {
+        self.path.push('>');
+        self.push_metric(agg, metric);
+        self
+    }
+
+    /// End the path at the document count of the buckets inside the current path
+    pub fn then_count(mut self) -> Self {
+        self.path.push_str(">_count");
+        self
+    }
+
+    /// The path string
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+
+    fn push_metric(&mut self, agg: &str, metric: Option<&str>) {
+        self.path.push_str(agg);
+        match metric {
+            None => {}
+            // Metric names with dots, like the `99.9` percentile, need the bracket form
+            Some(metric) if metric.contains('.') => {
+                self.path.push('[');
+                self.path.push_str(metric);
+                self.path.push(']');
+            }
+            Some(metric) => {
+                self.path.push('.');
+                self.path.push_str(metric);
+            }
+        }
+    }
+}
+
+impl Display for BucketsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+impl From<BucketsPath> for Cow<'static, str> {
+    fn from(path: BucketsPath) -> Self {
+        Cow::Owned(path.path)
+    }
+}
+
+impl From<BucketsPath> for String {
+    fn from(path: BucketsPath) -> Self {
+        path.path
+    }
+}
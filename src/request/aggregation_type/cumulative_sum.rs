@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Cumulative Sum Aggregation
+///
+/// A pipeline aggregation keeping a running total of a metric across the buckets of its parent
+/// histogram.
+#[derive(Debug, Clone, Serialize)]
+pub struct CumulativeSumAggregation<'a> {
+    /// The path to the metric to sum, see [`BucketsPath`](crate::BucketsPath)
+    #[serde(borrow)]
+    pub buckets_path: Cow<'a, str>,
+}
+
+impl<'a> CumulativeSumAggregation<'a> {
+    /// Create a new CumulativeSumAggregation
+    pub fn new(buckets_path: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            buckets_path: buckets_path.into(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for CumulativeSumAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        let mut cumulative_sum_obj = Map::new();
+        cumulative_sum_obj.insert(
+            "buckets_path".to_string(),
+            Value::String(self.buckets_path.to_string()),
+        );
+        result.insert(
+            "cumulative_sum".to_string(),
+            Value::Object(cumulative_sum_obj),
+        );
+        Value::Object(result)
+    }
+}
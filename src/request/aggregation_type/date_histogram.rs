@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, DateFormat, ToOpenSearchJson};
+
+/// Date Histogram Aggregation
+#[derive(Debug, Clone, Serialize)]
+pub struct DateHistogramAggregation<'a> {
+    /// The date field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// A calendar-aware interval, e.g. `1d` or `month`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar_interval: Option<Cow<'a, str>>,
+    /// A fixed interval in SI units, e.g. `30m` or `12h`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_interval: Option<Cow<'a, str>>,
+    /// The date format of the bucket keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<DateFormat<'a>>,
+    /// The time zone buckets are computed in, e.g. `+01:00` or `Europe/Amsterdam`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Cow<'a, str>>,
+    /// The minimum number of documents for a bucket to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_doc_count: Option<u64>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> DateHistogramAggregation<'a> {
+    /// Create a new DateHistogramAggregation with a calendar-aware interval
+    pub fn calendar(field: impl Into<Cow<'a, str>>, interval: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            calendar_interval: Some(interval.into()),
+            ..Self::without_interval(field)
+        }
+    }
+
+    /// Create a new DateHistogramAggregation with a fixed interval
+    pub fn fixed(field: impl Into<Cow<'a, str>>, interval: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            fixed_interval: Some(interval.into()),
+            ..Self::without_interval(field)
+        }
+    }
+
+    fn without_interval(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            calendar_interval: None,
+            fixed_interval: None,
+            format: None,
+            time_zone: None,
+            min_doc_count: None,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Set the date format of the bucket keys
+    pub fn format(mut self, format: impl Into<DateFormat<'a>>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the time zone buckets are computed in
+    pub fn time_zone(mut self, time_zone: impl Into<Cow<'a, str>>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// Set the minimum number of documents for a bucket to be returned
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for DateHistogramAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut histogram_obj = Map::new();
+        histogram_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+
+        if let Some(ref calendar_interval) = self.calendar_interval {
+            histogram_obj.insert(
+                "calendar_interval".to_string(),
+                Value::String(calendar_interval.to_string()),
+            );
+        }
+        if let Some(ref fixed_interval) = self.fixed_interval {
+            histogram_obj.insert(
+                "fixed_interval".to_string(),
+                Value::String(fixed_interval.to_string()),
+            );
+        }
+        if let Some(ref format) = self.format {
+            histogram_obj.insert("format".to_string(), Value::String(format.to_string()));
+        }
+        if let Some(ref time_zone) = self.time_zone {
+            histogram_obj.insert(
+                "time_zone".to_string(),
+                Value::String(time_zone.to_string()),
+            );
+        }
+        if let Some(min_doc_count) = self.min_doc_count {
+            histogram_obj.insert(
+                "min_doc_count".to_string(),
+                Value::Number(min_doc_count.into()),
+            );
+        }
+
+        let mut result = Map::new();
+        result.insert("date_histogram".to_string(), Value::Object(histogram_obj));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
+
+/// Builder pattern for DateHistogramAggregation that allows dynamic updates.
+#[derive(Debug, Clone)]
+pub struct DateHistogramAggregationBuilder<'a> {
+    field: Cow<'a, str>,
+    calendar_interval: Option<Cow<'a, str>>,
+    fixed_interval: Option<Cow<'a, str>>,
+    format: Option<DateFormat<'a>>,
+    time_zone: Option<Cow<'a, str>>,
+    min_doc_count: Option<u64>,
+    sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> DateHistogramAggregationBuilder<'a> {
+    /// Create a new DateHistogramAggregationBuilder with a calendar-aware interval
+    pub fn calendar(field: impl Into<Cow<'a, str>>, interval: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            calendar_interval: Some(interval.into()),
+            ..Self::without_interval(field)
+        }
+    }
+
+    /// Create a new DateHistogramAggregationBuilder with a fixed interval
+    pub fn fixed(field: impl Into<Cow<'a, str>>, interval: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            fixed_interval: Some(interval.into()),
+            ..Self::without_interval(field)
+        }
+    }
+
+    fn without_interval(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            calendar_interval: None,
+            fixed_interval: None,
+            format: None,
+            time_zone: None,
+            min_doc_count: None,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Set the date format of the bucket keys (replaces existing format)
+    pub fn format(&mut self, format: impl Into<DateFormat<'a>>) -> &mut Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the time zone buckets are computed in (replaces existing time zone)
+    pub fn time_zone(&mut self, time_zone: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// Set the minimum number of documents for a bucket to be returned
+    pub fn min_doc_count(&mut self, min_doc_count: u64) -> &mut Self {
+        self.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        agg: AggregationType<'a>,
+    ) -> &mut Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+
+    /// Build the final DateHistogramAggregation
+    pub fn build(self) -> DateHistogramAggregation<'a> {
+        DateHistogramAggregation {
+            field: self.field,
+            calendar_interval: self.calendar_interval,
+            fixed_interval: self.fixed_interval,
+            format: self.format,
+            time_zone: self.time_zone,
+            min_doc_count: self.min_doc_count,
+            sub_aggs: self.sub_aggs,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Derivative Aggregation
+///
+/// A pipeline aggregation computing the change of a metric between consecutive buckets of its
+/// parent histogram.
+#[derive(Debug, Clone, Serialize)]
+pub struct DerivativeAggregation<'a> {
+    /// The path to the metric to differentiate, see [`BucketsPath`](crate::BucketsPath)
+    #[serde(borrow)]
+    pub buckets_path: Cow<'a, str>,
+    /// The time unit to normalize the change to, e.g. `1d`, for a date histogram parent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<Cow<'a, str>>,
+}
+
+impl<'a> DerivativeAggregation<'a> {
+    /// Create a new DerivativeAggregation
+    pub fn new(buckets_path: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            buckets_path: buckets_path.into(),
+            unit: None,
+        }
+    }
+
+    /// Set the time unit to normalize the change to
+    pub fn unit(mut self, unit: impl Into<Cow<'a, str>>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for DerivativeAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut derivative_obj = Map::new();
+        derivative_obj.insert(
+            "buckets_path".to_string(),
+            Value::String(self.buckets_path.to_string()),
+        );
+
+        if let Some(ref unit) = self.unit {
+            derivative_obj.insert("unit".to_string(), Value::String(unit.to_string()));
+        }
+
+        let mut result = Map::new();
+        result.insert("derivative".to_string(), Value::Object(derivative_obj));
+        Value::Object(result)
+    }
+}
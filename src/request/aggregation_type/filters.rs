@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, QueryType, ToOpenSearchJson};
+
+/// The filters of a filters aggregation
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AggregationFilters<'a> {
+    /// Filters keyed by name, producing a bucket per name
+    ///
+    /// Stored in a sorted map so the generated buckets are always emitted in the same order.
+    Named(#[serde(borrow)] BTreeMap<Cow<'a, str>, QueryType<'a>>),
+    /// Unnamed filters, producing buckets in the same order as the filters
+    Anonymous(#[serde(borrow)] Vec<QueryType<'a>>),
+}
+
+impl<'a> AggregationFilters<'a> {
+    /// Create named filters
+    pub fn named<I, K>(filters: I) -> Self
+    where
+        I: IntoIterator<Item = (K, QueryType<'a>)>,
+        K: Into<Cow<'a, str>>,
+    {
+        AggregationFilters::Named(
+            filters
+                .into_iter()
+                .map(|(name, query)| (name.into(), query))
+                .collect(),
+        )
+    }
+
+    /// Create anonymous filters
+    pub fn anonymous(filters: impl IntoIterator<Item = QueryType<'a>>) -> Self {
+        AggregationFilters::Anonymous(filters.into_iter().collect())
+    }
+}
+
+impl<'a> ToOpenSearchJson for AggregationFilters<'a> {
+    fn to_json(&self) -> Value {
+        match self {
+            AggregationFilters::Named(filters) => {
+                let mut filters_obj = Map::new();
+                for (name, query) in filters {
+                    filters_obj.insert(name.to_string(), query.to_json());
+                }
+                Value::Object(filters_obj)
+            }
+            AggregationFilters::Anonymous(filters) => {
+                Value::Array(filters.iter().map(|q| q.to_json()).collect())
+            }
+        }
+    }
+}
+
+/// Filters Aggregation
+///
+/// Creates a bucket for each filter, containing the documents matching it.
+#[derive(Debug, Clone, Serialize)]
+pub struct FiltersAggregation<'a> {
+    /// The filters to bucket by
+    #[serde(borrow)]
+    pub filters: AggregationFilters<'a>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> FiltersAggregation<'a> {
+    /// Create a new FiltersAggregation
+    pub fn new(filters: AggregationFilters<'a>) -> Self {
+        Self {
+            filters,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for FiltersAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut filters_obj = Map::new();
+        filters_obj.insert("filters".to_string(), self.filters.to_json());
+
+        let mut result = Map::new();
+        result.insert("filters".to_string(), Value::Object(filters_obj));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
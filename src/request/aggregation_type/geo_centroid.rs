@@ -0,0 +1,35 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Geo Centroid Aggregation
+///
+/// The weighted center of the geo points in a field.
+#[derive(Debug, Clone, Serialize)]
+pub struct GeoCentroidAggregation<'a> {
+    /// The geo point field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+}
+
+impl<'a> GeoCentroidAggregation<'a> {
+    /// Create a new GeoCentroidAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for GeoCentroidAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        let mut geo_centroid_obj = Map::new();
+        geo_centroid_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        result.insert("geo_centroid".to_string(), Value::Object(geo_centroid_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, DistanceUnit, GeoPoint, RangeBucket, ToOpenSearchJson};
+
+/// Geo Distance Aggregation
+///
+/// Buckets documents into bands by their distance from an origin point.
+#[derive(Debug, Clone, Serialize)]
+pub struct GeoDistanceAggregation<'a> {
+    /// The geo point field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The point distances are measured from
+    pub origin: GeoPoint,
+    /// The distance bands
+    #[serde(borrow)]
+    pub ranges: Vec<RangeBucket<'a>>,
+    /// The unit the range bounds are in, meters when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<DistanceUnit>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> GeoDistanceAggregation<'a> {
+    /// Create a new GeoDistanceAggregation without any bands
+    pub fn new(field: impl Into<Cow<'a, str>>, origin: GeoPoint) -> Self {
+        Self {
+            field: field.into(),
+            origin,
+            ranges: Vec::new(),
+            unit: None,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Add a distance band
+    pub fn range(mut self, range: RangeBucket<'a>) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    /// Set the unit the range bounds are in
+    pub fn unit(mut self, unit: DistanceUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for GeoDistanceAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut geo_distance_obj = Map::new();
+        geo_distance_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        geo_distance_obj.insert("origin".to_string(), self.origin.to_json());
+
+        let ranges: Vec<Value> = self.ranges.iter().map(|r| r.to_json()).collect();
+        geo_distance_obj.insert("ranges".to_string(), Value::Array(ranges));
+
+        if let Some(unit) = self.unit {
+            geo_distance_obj.insert("unit".to_string(), Value::String(unit.to_string()));
+        }
+
+        let mut result = Map::new();
+        result.insert("geo_distance".to_string(), Value::Object(geo_distance_obj));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
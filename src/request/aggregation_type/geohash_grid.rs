@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, ToOpenSearchJson};
+
+/// Geohash Grid Aggregation
+///
+/// Buckets geo points into the geohash cells they fall in, e.g. for map heatmaps.
+#[derive(Debug, Clone, Serialize)]
+pub struct GeohashGridAggregation<'a> {
+    /// The geo point field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The geohash length of the cells, from 1 to 12
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u8>,
+    /// The maximum number of cells to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> GeohashGridAggregation<'a> {
+    /// Create a new GeohashGridAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            precision: None,
+            size: None,
+            sub_aggs: HashMap::new(),
+        }
+    }
+
+    /// Set the geohash length of the cells
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set the maximum number of cells to return
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for GeohashGridAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut geohash_grid_obj = Map::new();
+        geohash_grid_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+
+        if let Some(precision) = self.precision {
+            geohash_grid_obj.insert("precision".to_string(), Value::Number(precision.into()));
+        }
+        if let Some(size) = self.size {
+            geohash_grid_obj.insert("size".to_string(), Value::Number(size.into()));
+        }
+
+        let mut result = Map::new();
+        result.insert("geohash_grid".to_string(), Value::Object(geohash_grid_obj));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, ToOpenSearchJson};
+
+/// Global Aggregation
+///
+/// Runs its sub-aggregations over every document in the index, ignoring the search query.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GlobalAggregation<'a> {
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default, borrow)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> GlobalAggregation<'a> {
+    /// Create a new GlobalAggregation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for GlobalAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("global".to_string(), Value::Object(Map::new()));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
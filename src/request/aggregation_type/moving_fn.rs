@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Moving Function Aggregation
+///
+/// A pipeline aggregation running a script over a sliding window of a metric across the buckets
+/// of its parent histogram, e.g. `MovingFunctions.unweightedAvg(values)` for a moving average.
+#[derive(Debug, Clone, Serialize)]
+pub struct MovingFnAggregation<'a> {
+    /// The path to the metric to slide over, see [`BucketsPath`](crate::BucketsPath)
+    #[serde(borrow)]
+    pub buckets_path: Cow<'a, str>,
+    /// The number of buckets in the window
+    pub window: u32,
+    /// The script run on each window
+    #[serde(borrow)]
+    pub script: Cow<'a, str>,
+}
+
+impl<'a> MovingFnAggregation<'a> {
+    /// Create a new MovingFnAggregation
+    pub fn new(
+        buckets_path: impl Into<Cow<'a, str>>,
+        window: u32,
+        script: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            buckets_path: buckets_path.into(),
+            window,
+            script: script.into(),
+        }
+    }
+
+    /// Create a MovingFnAggregation computing the unweighted moving average over `window` buckets
+    pub fn moving_avg(buckets_path: impl Into<Cow<'a, str>>, window: u32) -> Self {
+        Self::new(
+            buckets_path,
+            window,
+            "MovingFunctions.unweightedAvg(values)",
+        )
+    }
+}
+
+impl<'a> ToOpenSearchJson for MovingFnAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut moving_fn_obj = Map::new();
+        moving_fn_obj.insert(
+            "buckets_path".to_string(),
+            Value::String(self.buckets_path.to_string()),
+        );
+        moving_fn_obj.insert("window".to_string(), Value::Number(self.window.into()));
+        moving_fn_obj.insert("script".to_string(), Value::String(self.script.to_string()));
+
+        let mut result = Map::new();
+        result.insert("moving_fn".to_string(), Value::Object(moving_fn_obj));
+        Value::Object(result)
+    }
+}
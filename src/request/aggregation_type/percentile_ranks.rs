@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Percentile Ranks Aggregation
+#[derive(Debug, Clone, Serialize)]
+pub struct PercentileRanksAggregation<'a> {
+    /// The field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The values to compute the percentile rank of
+    pub values: Vec<f64>,
+}
+
+impl<'a> PercentileRanksAggregation<'a> {
+    /// Create a new PercentileRanksAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>, values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            field: field.into(),
+            values: values.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for PercentileRanksAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut percentile_ranks_obj = Map::new();
+        percentile_ranks_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        percentile_ranks_obj.insert(
+            "values".to_string(),
+            Value::Array(self.values.iter().map(|v| (*v).into()).collect()),
+        );
+
+        let mut result = Map::new();
+        result.insert(
+            "percentile_ranks".to_string(),
+            Value::Object(percentile_ranks_obj),
+        );
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+use crate::util::finite;
+
+/// A bucket of a range-style aggregation, `from` inclusive and `to` exclusive
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RangeBucket<'a> {
+    /// The key the bucket is returned under, generated from the bounds when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<Cow<'a, str>>,
+    /// The lower bound, unbounded when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<f64>,
+    /// The upper bound, unbounded when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<f64>,
+}
+
+impl<'a> RangeBucket<'a> {
+    /// Create a new unbounded RangeBucket
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a RangeBucket bounded on both sides
+    pub fn between(from: f64, to: f64) -> Self {
+        Self::new().from(from).to(to)
+    }
+
+    /// Set the key the bucket is returned under
+    pub fn key(mut self, key: impl Into<Cow<'a, str>>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Set the lower bound
+    pub fn from(mut self, from: f64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Set the upper bound
+    pub fn to(mut self, to: f64) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for RangeBucket<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+
+        if let Some(ref key) = self.key {
+            result.insert("key".to_string(), Value::String(key.to_string()));
+        }
+        if let Some(from) = self.from.and_then(finite) {
+            result.insert("from".to_string(), from.into());
+        }
+        if let Some(to) = self.to.and_then(finite) {
+            result.insert("to".to_string(), to.into());
+        }
+
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{AggregationType, ToOpenSearchJson};
+
+/// Sampler Aggregation
+///
+/// Limits its sub-aggregations to the top-scoring documents on each shard.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SamplerAggregation<'a> {
+    /// The number of top-scoring documents to sample per shard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard_size: Option<u32>,
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "HashMap::is_empty", default, borrow)]
+    pub sub_aggs: HashMap<Cow<'a, str>, AggregationType<'a>>,
+}
+
+impl<'a> SamplerAggregation<'a> {
+    /// Create a new SamplerAggregation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of top-scoring documents to sample per shard
+    pub fn shard_size(mut self, shard_size: u32) -> Self {
+        self.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Add a sub-aggregation
+    pub fn sub_agg(mut self, name: impl Into<Cow<'a, str>>, agg: AggregationType<'a>) -> Self {
+        self.sub_aggs.insert(name.into(), agg);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for SamplerAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut sampler_obj = Map::new();
+
+        if let Some(shard_size) = self.shard_size {
+            sampler_obj.insert("shard_size".to_string(), Value::Number(shard_size.into()));
+        }
+
+        let mut result = Map::new();
+        result.insert("sampler".to_string(), Value::Object(sampler_obj));
+
+        if !self.sub_aggs.is_empty() {
+            let mut aggs_obj = Map::new();
+            for (name, agg) in &self.sub_aggs {
+                aggs_obj.insert(name.to_string(), agg.to_json());
+            }
+            result.insert("aggs".to_string(), Value::Object(aggs_obj));
+        }
+
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Sum Aggregation
+#[derive(Debug, Clone, Serialize)]
+pub struct SumAggregation<'a> {
+    /// The field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+}
+
+impl<'a> SumAggregation<'a> {
+    /// Create a new SumAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for SumAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        let mut sum_obj = Map::new();
+        sum_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        result.insert("sum".to_string(), Value::Object(sum_obj));
+        Value::Object(result)
+    }
+}
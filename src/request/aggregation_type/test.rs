@@ -0,0 +1,643 @@
+use super::*;
+use crate::{
+    DateFormat, DistanceUnit, FieldSort, GeoPoint, QueryType, SearchRequest, SortOrder, SortType,
+};
+
+#[test]
+fn test_value_count_aggregation() {
+    let agg = AggregationType::ValueCount(ValueCountAggregation::new("user_id"));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "value_count": {
+                "field": "user_id"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_value_count_alongside_cardinality() {
+    let request = SearchRequest::new()
+        .agg(
+            "unique_users",
+            AggregationType::Cardinality(CardinalityAggregation::new("user_id")),
+        )
+        .agg(
+            "total_users",
+            AggregationType::ValueCount(ValueCountAggregation::new("user_id")),
+        );
+
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "aggs": {
+                "unique_users": {
+                    "cardinality": {
+                        "field": "user_id"
+                    }
+                },
+                "total_users": {
+                    "value_count": {
+                        "field": "user_id"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_percentile_ranks_aggregation() {
+    let agg = AggregationType::PercentileRanks(PercentileRanksAggregation::new(
+        "latency",
+        [500.0, 1000.0],
+    ));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "percentile_ranks": {
+                "field": "latency",
+                "values": [500.0, 1000.0]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_top_metrics_aggregation() {
+    let agg = AggregationType::TopMetrics(TopMetricsAggregation::new(
+        ["price"],
+        SortType::Field(FieldSort::new("updated_at", SortOrder::Desc)),
+    ));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "top_metrics": {
+                "metrics": [{"field": "price"}],
+                "sort": [{"updated_at": "desc"}]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_adjacency_matrix_aggregation() {
+    // Filters are inserted out of order to check the output is sorted by name
+    let agg = AggregationType::AdjacencyMatrix(
+        AdjacencyMatrixAggregation::new()
+            .filter("c", QueryType::term("accounts", "carol"))
+            .filter("a", QueryType::term("accounts", "alice"))
+            .filter("b", QueryType::term("accounts", "bob"))
+            .separator("&"),
+    );
+
+    let result = agg.to_json();
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "adjacency_matrix": {
+                "filters": {
+                    "a": {"term": {"accounts": "alice"}},
+                    "b": {"term": {"accounts": "bob"}},
+                    "c": {"term": {"accounts": "carol"}}
+                },
+                "separator": "&"
+            }
+        })
+    );
+
+    let names: Vec<&String> = result["adjacency_matrix"]["filters"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .collect();
+    assert_eq!(names, ["a", "b", "c"]);
+    assert_eq!(
+        result.to_string(),
+        r#"{"adjacency_matrix":{"filters":{"a":{"term":{"accounts":"alice"}},"b":{"term":{"accounts":"bob"}},"c":{"term":{"accounts":"carol"}}},"separator":"&"}}"#
+    );
+}
+
+#[test]
+fn test_sampler_with_significant_terms() {
+    let agg = AggregationType::Sampler(SamplerAggregation::new().shard_size(200).sub_agg(
+        "keywords",
+        AggregationType::SignificantTerms(SignificantTermsAggregation::new("tags").size(5)),
+    ));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "sampler": {
+                "shard_size": 200
+            },
+            "aggs": {
+                "keywords": {
+                    "significant_terms": {
+                        "field": "tags",
+                        "size": 5
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_meta_is_sibling_of_agg_body() {
+    let mut meta = serde_json::Map::new();
+    meta.insert("color".to_string(), "blue".into());
+
+    let agg = AggregationType::Terms(
+        TermsAggregation::new("category")
+            .meta(meta.clone())
+            .sub_agg(
+                "unique_users",
+                AggregationType::Cardinality(CardinalityAggregation::new("user_id").meta(meta)),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "category"
+            },
+            "meta": {"color": "blue"},
+            "aggs": {
+                "unique_users": {
+                    "cardinality": {
+                        "field": "user_id"
+                    },
+                    "meta": {"color": "blue"}
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_group_by() {
+    let agg = AggregationType::group_by("author", 3);
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "author"
+            },
+            "aggs": {
+                "top_hits": {
+                    "top_hits": {
+                        "size": 3
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_aggregation_builder_conditional_sub_agg() {
+    let build = |with_users: bool| {
+        let mut builder = TermsAggregationBuilder::new("category");
+        builder.size(5);
+        if with_users {
+            builder.sub_agg(
+                "unique_users",
+                AggregationType::Cardinality(CardinalityAggregation::new("user_id")),
+            );
+        }
+        AggregationType::Terms(builder.build())
+    };
+
+    assert_eq!(
+        build(false).to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "category",
+                "size": 5
+            }
+        })
+    );
+    assert_eq!(
+        build(true).to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "category",
+                "size": 5
+            },
+            "aggs": {
+                "unique_users": {
+                    "cardinality": {
+                        "field": "user_id"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_missing_and_order() {
+    let agg = AggregationType::Terms(
+        TermsAggregation::new("category")
+            .missing("N/A")
+            .order("_count", SortOrder::Desc)
+            .size(10),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "category",
+                "missing": "N/A",
+                "order": {"_count": "desc"},
+                "size": 10
+            }
+        })
+    );
+}
+
+#[test]
+fn test_terms_format() {
+    let agg = AggregationType::Terms(TermsAggregation::new("published_at").format("yyyy-MM"));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "terms": {
+                "field": "published_at",
+                "format": "yyyy-MM"
+            }
+        })
+    );
+
+    // Numeric fields take a decimal pattern
+    let agg = AggregationType::Terms(TermsAggregation::new("price").format("0.00"));
+    assert_eq!(agg.to_json()["terms"]["format"], "0.00");
+}
+
+#[test]
+fn test_buckets_path_single_metric() {
+    assert_eq!(BucketsPath::metric("sales", None).as_str(), "sales");
+    assert_eq!(
+        BucketsPath::metric("sales", Some("value")).to_string(),
+        "sales.value"
+    );
+    assert_eq!(
+        BucketsPath::metric("load_time", Some("99.9")).as_str(),
+        "load_time[99.9]"
+    );
+    assert_eq!(BucketsPath::count().as_str(), "_count");
+}
+
+#[test]
+fn test_buckets_path_multi_bucket() {
+    let path = BucketsPath::bucket("sales_per_month").then_metric("sales", Some("avg"));
+    assert_eq!(path.as_str(), "sales_per_month>sales.avg");
+
+    let path = BucketsPath::bucket("by_region")
+        .then_bucket("by_month")
+        .then_count();
+    assert_eq!(String::from(path), "by_region>by_month>_count");
+}
+
+#[test]
+fn test_geo_distance_aggregation() {
+    let agg = AggregationType::GeoDistance(
+        GeoDistanceAggregation::new("location", GeoPoint::new(52.37, 4.89))
+            .unit(DistanceUnit::Kilometers)
+            .range(RangeBucket::new().to(5.0).key("near"))
+            .range(RangeBucket::between(5.0, 25.0))
+            .range(RangeBucket::new().from(25.0))
+            .sub_agg(
+                "stores",
+                AggregationType::ValueCount(ValueCountAggregation::new("store_id")),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "geo_distance": {
+                "field": "location",
+                "origin": { "lat": 52.37, "lon": 4.89 },
+                "ranges": [
+                    { "key": "near", "to": 5.0 },
+                    { "from": 5.0, "to": 25.0 },
+                    { "from": 25.0 }
+                ],
+                "unit": "km"
+            },
+            "aggs": {
+                "stores": { "value_count": { "field": "store_id" } }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_geohash_grid_aggregation() {
+    let agg = AggregationType::GeohashGrid(
+        GeohashGridAggregation::new("location")
+            .precision(7)
+            .sub_agg(
+                "center",
+                AggregationType::GeoCentroid(GeoCentroidAggregation::new("location")),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "geohash_grid": {
+                "field": "location",
+                "precision": 7
+            },
+            "aggs": {
+                "center": { "geo_centroid": { "field": "location" } }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_auto_date_histogram_aggregation() {
+    let agg = AggregationType::AutoDateHistogram(
+        AutoDateHistogramAggregation::new("timestamp", 30)
+            .format(DateFormat::STRICT_DATE)
+            .sub_agg(
+                "revenue",
+                AggregationType::Sum(SumAggregation::new("price")),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "auto_date_histogram": {
+                "field": "timestamp",
+                "buckets": 30,
+                "format": "strict_date"
+            },
+            "aggs": {
+                "revenue": { "sum": { "field": "price" } }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_date_histogram_format() {
+    let agg = AggregationType::DateHistogram(
+        DateHistogramAggregation::calendar("timestamp", "month")
+            .format(DateFormat::STRICT_YEAR_MONTH),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "date_histogram": {
+                "field": "timestamp",
+                "calendar_interval": "month",
+                "format": "strict_year_month"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_date_histogram_aggregation_builder() {
+    let build = |with_revenue: bool| {
+        let mut builder = DateHistogramAggregationBuilder::fixed("timestamp", "12h");
+        builder.time_zone("Europe/Amsterdam").min_doc_count(1);
+        if with_revenue {
+            builder.sub_agg(
+                "revenue",
+                AggregationType::Sum(SumAggregation::new("price")),
+            );
+        }
+        AggregationType::DateHistogram(builder.build())
+    };
+
+    assert_eq!(
+        build(false).to_json(),
+        serde_json::json!({
+            "date_histogram": {
+                "field": "timestamp",
+                "fixed_interval": "12h",
+                "time_zone": "Europe/Amsterdam",
+                "min_doc_count": 1
+            }
+        })
+    );
+    assert_eq!(
+        build(true).to_json()["aggs"],
+        serde_json::json!({
+            "revenue": { "sum": { "field": "price" } }
+        })
+    );
+}
+
+#[test]
+fn test_cumulative_sum_aggregation() {
+    let agg = AggregationType::DateHistogram(
+        DateHistogramAggregation::calendar("timestamp", "month")
+            .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+            .sub_agg(
+                "cumulative_sales",
+                AggregationType::CumulativeSum(CumulativeSumAggregation::new(BucketsPath::metric(
+                    "sales", None,
+                ))),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "date_histogram": {
+                "field": "timestamp",
+                "calendar_interval": "month"
+            },
+            "aggs": {
+                "sales": { "sum": { "field": "price" } },
+                "cumulative_sales": { "cumulative_sum": { "buckets_path": "sales" } }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_derivative_aggregation() {
+    let agg = AggregationType::DateHistogram(
+        DateHistogramAggregation::calendar("timestamp", "month")
+            .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+            .sub_agg(
+                "sales_per_day",
+                AggregationType::Derivative(DerivativeAggregation::new("sales").unit("1d")),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json()["aggs"],
+        serde_json::json!({
+            "sales": { "sum": { "field": "price" } },
+            "sales_per_day": {
+                "derivative": {
+                    "buckets_path": "sales",
+                    "unit": "1d"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_moving_fn_aggregation() {
+    let agg = AggregationType::DateHistogram(
+        DateHistogramAggregation::calendar("timestamp", "1d")
+            .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+            .sub_agg(
+                "sales_moving_avg",
+                AggregationType::MovingFn(MovingFnAggregation::new(
+                    "sales",
+                    7,
+                    "MovingFunctions.unweightedAvg(values)",
+                )),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json()["aggs"]["sales_moving_avg"],
+        serde_json::json!({
+            "moving_fn": {
+                "buckets_path": "sales",
+                "window": 7,
+                "script": "MovingFunctions.unweightedAvg(values)"
+            }
+        })
+    );
+    assert_eq!(
+        AggregationType::MovingFn(MovingFnAggregation::moving_avg("sales", 7)).to_json(),
+        agg.to_json()["aggs"]["sales_moving_avg"]
+    );
+}
+
+#[test]
+fn test_bucket_selector_aggregation() {
+    let agg = AggregationType::Terms(
+        TermsAggregation::new("tags").sub_agg(
+            "popular_only",
+            AggregationType::BucketSelector(
+                BucketSelectorAggregation::new("params.count > 100")
+                    .buckets_path("count", BucketsPath::count()),
+            ),
+        ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "terms": { "field": "tags" },
+            "aggs": {
+                "popular_only": {
+                    "bucket_selector": {
+                        "buckets_path": { "count": "_count" },
+                        "script": "params.count > 100"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_bucket_sort_aggregation() {
+    let agg = AggregationType::DateHistogram(
+        DateHistogramAggregation::calendar("timestamp", "month")
+            .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+            .sub_agg(
+                "top_months",
+                AggregationType::BucketSort(
+                    BucketSortAggregation::new()
+                        .sort(SortType::Field(FieldSort::new("sales", SortOrder::Desc)))
+                        .size(3),
+                ),
+            ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "date_histogram": {
+                "field": "timestamp",
+                "calendar_interval": "month"
+            },
+            "aggs": {
+                "sales": { "sum": { "field": "price" } },
+                "top_months": {
+                    "bucket_sort": {
+                        "sort": [{ "sales": "desc" }],
+                        "size": 3
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_filters_aggregation_anonymous() {
+    let agg = AggregationType::Filters(FiltersAggregation::new(AggregationFilters::anonymous([
+        QueryType::term("level", "error"),
+        QueryType::term("level", "warning"),
+    ])));
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "filters": {
+                "filters": [
+                    { "term": { "level": "error" } },
+                    { "term": { "level": "warning" } }
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_filters_aggregation_named() {
+    let agg = AggregationType::Filters(
+        FiltersAggregation::new(AggregationFilters::named([
+            ("errors", QueryType::term("level", "error")),
+            ("warnings", QueryType::term("level", "warning")),
+        ]))
+        .sub_agg(
+            "hosts",
+            AggregationType::Cardinality(CardinalityAggregation::new("host")),
+        ),
+    );
+
+    assert_eq!(
+        agg.to_json(),
+        serde_json::json!({
+            "filters": {
+                "filters": {
+                    "errors": { "term": { "level": "error" } },
+                    "warnings": { "term": { "level": "warning" } }
+                }
+            },
+            "aggs": {
+                "hosts": { "cardinality": { "field": "host" } }
+            }
+        })
+    );
+}
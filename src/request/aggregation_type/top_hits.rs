@@ -0,0 +1,53 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{SortType, ToOpenSearchJson};
+
+/// Top Hits Aggregation
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TopHitsAggregation<'a> {
+    /// The number of top hits to return per bucket
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    /// The sort used to select the top hits
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub sort: Vec<SortType<'a>>,
+}
+
+impl<'a> TopHitsAggregation<'a> {
+    /// Create a new TopHitsAggregation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of top hits to return per bucket
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Add a sort criterion
+    pub fn sort(mut self, sort: SortType<'a>) -> Self {
+        self.sort.push(sort);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for TopHitsAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut top_hits_obj = Map::new();
+
+        if let Some(size) = self.size {
+            top_hits_obj.insert("size".to_string(), Value::Number(size.into()));
+        }
+
+        if !self.sort.is_empty() {
+            let sorts: Vec<Value> = self.sort.iter().map(|s| s.to_json()).collect();
+            top_hits_obj.insert("sort".to_string(), Value::Array(sorts));
+        }
+
+        let mut result = Map::new();
+        result.insert("top_hits".to_string(), Value::Object(top_hits_obj));
+        Value::Object(result)
+    }
+}
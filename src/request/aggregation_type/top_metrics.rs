@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{SortType, ToOpenSearchJson};
+
+/// Top Metrics Aggregation
+#[derive(Debug, Clone, Serialize)]
+pub struct TopMetricsAggregation<'a> {
+    /// The fields to return the values of
+    #[serde(borrow)]
+    pub metrics: Vec<Cow<'a, str>>,
+    /// The sort used to select the top documents
+    pub sort: Vec<SortType<'a>>,
+    /// The number of top documents to return metrics for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+impl<'a> TopMetricsAggregation<'a> {
+    /// Create a new TopMetricsAggregation
+    pub fn new<I>(metrics: I, sort: SortType<'a>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        Self {
+            metrics: metrics.into_iter().map(|m| m.into()).collect(),
+            sort: vec![sort],
+            size: None,
+        }
+    }
+
+    /// Add a sort criterion
+    pub fn sort(mut self, sort: SortType<'a>) -> Self {
+        self.sort.push(sort);
+        self
+    }
+
+    /// Set the number of top documents to return metrics for
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for TopMetricsAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut top_metrics_obj = Map::new();
+
+        let metrics: Vec<Value> = self
+            .metrics
+            .iter()
+            .map(|field| serde_json::json!({ "field": field.as_ref() }))
+            .collect();
+        top_metrics_obj.insert("metrics".to_string(), Value::Array(metrics));
+
+        let sorts: Vec<Value> = self.sort.iter().map(|s| s.to_json()).collect();
+        top_metrics_obj.insert("sort".to_string(), Value::Array(sorts));
+
+        if let Some(size) = self.size {
+            top_metrics_obj.insert("size".to_string(), Value::Number(size.into()));
+        }
+
+        let mut result = Map::new();
+        result.insert("top_metrics".to_string(), Value::Object(top_metrics_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Value Count Aggregation
+///
+/// Unlike [`CardinalityAggregation`](crate::CardinalityAggregation), which is an approximate
+/// distinct count, this counts every value extracted from the field exactly.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueCountAggregation<'a> {
+    /// The field to aggregate
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+}
+
+impl<'a> ValueCountAggregation<'a> {
+    /// Create a new ValueCountAggregation
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for ValueCountAggregation<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        let mut value_count_obj = Map::new();
+        value_count_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+        result.insert("value_count".to_string(), Value::Object(value_count_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,51 @@
+use std::cell::OnceCell;
+
+use serde_json::Value;
+
+use crate::{SearchRequest, ToOpenSearchJson};
+
+/// A SearchRequest whose JSON is computed once and reused.
+///
+/// The wrapper owns the request and never exposes it mutably, so the cached JSON can't go stale.
+#[derive(Debug, Clone)]
+pub struct CachedRequest<'a> {
+    request: SearchRequest<'a>,
+    json: OnceCell<Value>,
+}
+
+impl<'a> CachedRequest<'a> {
+    /// Wrap a SearchRequest
+    pub fn new(request: SearchRequest<'a>) -> Self {
+        Self {
+            request,
+            json: OnceCell::new(),
+        }
+    }
+
+    /// The wrapped request
+    pub fn request(&self) -> &SearchRequest<'a> {
+        &self.request
+    }
+
+    /// Borrow the JSON of the request, serializing it on first use
+    pub fn as_json(&self) -> &Value {
+        self.json.get_or_init(|| self.request.to_json())
+    }
+
+    /// Unwrap the request
+    pub fn into_inner(self) -> SearchRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> From<SearchRequest<'a>> for CachedRequest<'a> {
+    fn from(request: SearchRequest<'a>) -> Self {
+        Self::new(request)
+    }
+}
+
+impl<'a> ToOpenSearchJson for CachedRequest<'a> {
+    fn to_json(&self) -> Value {
+        self.as_json().clone()
+    }
+}
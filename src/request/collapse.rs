@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use serde::Serialize;
 use serde_json::{Map, Value};
 
-use crate::ToOpenSearchJson;
+use crate::{SortType, ToOpenSearchJson};
 
 /// Collapse
 #[derive(Debug, Clone, Serialize)]
@@ -11,6 +11,9 @@ pub struct Collapse<'a> {
     /// The field to collapse on
     #[serde(borrow)]
     pub field: Cow<'a, str>,
+    /// Inner hits to return for each collapsed group
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub inner_hits: Vec<InnerHits<'a>>,
 }
 
 impl<'a> Collapse<'a> {
@@ -18,14 +21,119 @@ impl<'a> Collapse<'a> {
     pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
         Self {
             field: field.into(),
+            inner_hits: Vec::new(),
         }
     }
+
+    /// Add an inner hits definition (can be called multiple times)
+    pub fn inner_hits(mut self, inner_hits: InnerHits<'a>) -> Self {
+        self.inner_hits.push(inner_hits);
+        self
+    }
 }
 
 impl<'a> ToOpenSearchJson for Collapse<'a> {
     fn to_json(&self) -> Value {
         let mut result = Map::new();
         result.insert("field".to_string(), Value::String(self.field.to_string()));
+
+        match self.inner_hits.as_slice() {
+            [] => {}
+            [inner_hits] => {
+                result.insert("inner_hits".to_string(), inner_hits.to_json());
+            }
+            inner_hits => {
+                let inner_hits: Vec<Value> = inner_hits.iter().map(|i| i.to_json()).collect();
+                result.insert("inner_hits".to_string(), Value::Array(inner_hits));
+            }
+        }
+
+        Value::Object(result)
+    }
+}
+
+/// Inner hits returned for each collapsed group
+#[derive(Debug, Clone, Serialize)]
+pub struct InnerHits<'a> {
+    /// The name of the inner hits in the response
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    /// The number of hits to return per group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    /// Offset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<u32>,
+    /// Sort criteria
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub sort: Vec<SortType<'a>>,
+    /// A second-level collapse applied to the inner hits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse: Option<Box<Collapse<'a>>>,
+}
+
+impl<'a> InnerHits<'a> {
+    /// Create a new InnerHits
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            size: None,
+            from: None,
+            sort: Vec::new(),
+            collapse: None,
+        }
+    }
+
+    /// Set the number of hits to return per group
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the offset
+    pub fn from(mut self, from: u32) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Add a sort criterion
+    pub fn sort(mut self, sort: SortType<'a>) -> Self {
+        self.sort.push(sort);
+        self
+    }
+
+    /// Collapse the inner hits on a second field
+    pub fn collapse(mut self, collapse: Collapse<'a>) -> Self {
+        self.collapse = Some(Box::new(collapse));
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for InnerHits<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("name".to_string(), Value::String(self.name.to_string()));
+
+        if let Some(size) = self.size {
+            result.insert("size".to_string(), Value::Number(size.into()));
+        }
+
+        if let Some(from) = self.from {
+            result.insert("from".to_string(), Value::Number(from.into()));
+        }
+
+        if !self.sort.is_empty() {
+            let sorts: Vec<Value> = self.sort.iter().map(|s| s.to_json()).collect();
+            result.insert("sort".to_string(), Value::Array(sorts));
+        }
+
+        if let Some(ref collapse) = self.collapse {
+            result.insert("collapse".to_string(), collapse.to_json());
+        }
+
         Value::Object(result)
     }
 }
+
+#[cfg(test)]
+mod test;
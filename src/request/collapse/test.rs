@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn test_collapse_without_inner_hits() {
+    assert_eq!(
+        Collapse::new("user_id").to_json(),
+        serde_json::json!({"field": "user_id"})
+    );
+}
+
+#[test]
+fn test_collapse_with_inner_hits() {
+    let collapse = Collapse::new("user_id").inner_hits(InnerHits::new("latest").size(3));
+
+    assert_eq!(
+        collapse.to_json(),
+        serde_json::json!({
+            "field": "user_id",
+            "inner_hits": {
+                "name": "latest",
+                "size": 3
+            }
+        })
+    );
+}
+
+#[test]
+fn test_two_level_collapse() {
+    let collapse = Collapse::new("user_id").inner_hits(
+        InnerHits::new("by_session")
+            .size(5)
+            .collapse(Collapse::new("session_id")),
+    );
+
+    assert_eq!(
+        collapse.to_json(),
+        serde_json::json!({
+            "field": "user_id",
+            "inner_hits": {
+                "name": "by_session",
+                "size": 5,
+                "collapse": {
+                    "field": "session_id"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_collapse_with_multiple_inner_hits() {
+    let collapse = Collapse::new("user_id")
+        .inner_hits(InnerHits::new("first").size(1))
+        .inner_hits(InnerHits::new("second").size(2));
+
+    assert_eq!(
+        collapse.to_json()["inner_hits"],
+        serde_json::json!([
+            {"name": "first", "size": 1},
+            {"name": "second", "size": 2}
+        ])
+    );
+}
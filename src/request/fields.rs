@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// A field to retrieve with the top-level `fields` option, optionally with a format
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldAndFormat<'a> {
+    /// The field name or wildcard pattern
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The format to return the values in, e.g. a date format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<Cow<'a, str>>,
+}
+
+impl<'a> FieldAndFormat<'a> {
+    /// Create a new FieldAndFormat
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            format: None,
+        }
+    }
+
+    /// Set the format to return the values in
+    pub fn format(mut self, format: impl Into<Cow<'a, str>>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+impl<'a> From<&'a str> for FieldAndFormat<'a> {
+    fn from(field: &'a str) -> Self {
+        FieldAndFormat::new(field)
+    }
+}
+
+impl From<String> for FieldAndFormat<'static> {
+    fn from(field: String) -> Self {
+        FieldAndFormat::new(field)
+    }
+}
+
+impl<'a> ToOpenSearchJson for FieldAndFormat<'a> {
+    fn to_json(&self) -> Value {
+        match self.format {
+            // Use the bare string form when there is no format
+            None => Value::String(self.field.to_string()),
+            Some(ref format) => {
+                let mut result = Map::new();
+                result.insert("field".to_string(), Value::String(self.field.to_string()));
+                result.insert("format".to_string(), Value::String(format.to_string()));
+                Value::Object(result)
+            }
+        }
+    }
+}
@@ -64,12 +64,66 @@ impl<'a> ToOpenSearchJson for Highlight<'a> {
     }
 }
 
+/// The highlighter used for a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HighlightType<'a> {
+    /// The unified highlighter, the default
+    Unified,
+    /// The plain highlighter
+    Plain,
+    /// The fast vector highlighter, requires term vectors with positions and offsets
+    Fvh,
+    /// A highlighter OpenSearch doesn't ship, rejected by [`crate::SearchRequest::validate`]
+    Other(Cow<'a, str>),
+}
+
+impl<'a> HighlightType<'a> {
+    /// The highlighter name as sent to OpenSearch
+    pub fn as_str(&self) -> &str {
+        match self {
+            HighlightType::Unified => "unified",
+            HighlightType::Plain => "plain",
+            HighlightType::Fvh => "fvh",
+            HighlightType::Other(other) => other,
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for HighlightType<'a> {
+    fn from(highlight_type: Cow<'a, str>) -> Self {
+        match highlight_type.as_ref() {
+            "unified" => HighlightType::Unified,
+            "plain" => HighlightType::Plain,
+            "fvh" => HighlightType::Fvh,
+            _ => HighlightType::Other(highlight_type),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HighlightType<'a> {
+    fn from(highlight_type: &'a str) -> Self {
+        Cow::Borrowed(highlight_type).into()
+    }
+}
+
+impl From<String> for HighlightType<'static> {
+    fn from(highlight_type: String) -> Self {
+        Cow::<'static, str>::Owned(highlight_type).into()
+    }
+}
+
+impl<'a> Serialize for HighlightType<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 /// HighlightField
 #[derive(Debug, Clone, Serialize)]
 pub struct HighlightField<'a> {
     /// Highlight type
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
-    pub highlight_type: Option<Cow<'a, str>>,
+    pub highlight_type: Option<HighlightType<'a>>,
     /// Number of fragments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub number_of_fragments: Option<u32>,
@@ -99,7 +153,7 @@ impl<'a> HighlightField<'a> {
     }
 
     /// Set the highlight type
-    pub fn highlight_type(mut self, highlight_type: impl Into<Cow<'a, str>>) -> Self {
+    pub fn highlight_type(mut self, highlight_type: impl Into<HighlightType<'a>>) -> Self {
         self.highlight_type = Some(highlight_type.into());
         self
     }
@@ -138,7 +192,7 @@ impl<'a> ToOpenSearchJson for HighlightField<'a> {
         if let Some(ref highlight_type) = self.highlight_type {
             result.insert(
                 "type".to_string(),
-                Value::String(highlight_type.to_string()),
+                Value::String(highlight_type.as_str().to_string()),
             );
         }
 
@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::{AggregationFilters, AggregationType, SearchRequest, SortType};
+
+impl<'a> SearchRequest<'a> {
+    /// Return a copy of the request with every field name passed through `f`, e.g. to prefix all
+    /// fields with a tenant name.
+    ///
+    /// The query, sorts and aggregations are rewritten, including sub-aggregations, the sorts of
+    /// top hits and top metrics, and the filters of adjacency matrix and filters aggregations.
+    /// Source filtering, `fields`, highlighting, collapsing and runtime mappings are left
+    /// unchanged.
+    pub fn map_fields<F: Fn(&str) -> String>(&self, f: &F) -> SearchRequest<'a> {
+        let mut request = self.clone();
+        if let Some(ref mut query) = request.query {
+            query.map_fields_in_place(f);
+        }
+        map_sort_fields(request.sort.to_mut(), f);
+        map_agg_fields(&mut request.aggs, f);
+        request
+    }
+}
+
+fn map_sort_fields<F: Fn(&str) -> String>(sort: &mut [SortType<'_>], f: &F) {
+    for sort in sort {
+        match sort {
+            SortType::Field(field_sort) => field_sort.field = Cow::Owned(f(&field_sort.field)),
+            SortType::GeoDistance(geo_distance) => {
+                geo_distance.field = Cow::Owned(f(&geo_distance.field))
+            }
+            SortType::Score
+            | SortType::Doc
+            | SortType::ScoreWithOrder(_)
+            | SortType::ScriptSort(_) => {}
+        }
+    }
+}
+
+fn map_agg_fields<F: Fn(&str) -> String>(
+    aggs: &mut HashMap<Cow<'_, str>, AggregationType<'_>>,
+    f: &F,
+) {
+    for agg in aggs.values_mut() {
+        let map = |field: &mut Cow<'_, str>| *field = Cow::Owned(f(field));
+
+        match agg {
+            AggregationType::Terms(terms) => {
+                map(&mut terms.field);
+                map_agg_fields(&mut terms.sub_aggs, f);
+            }
+            AggregationType::Cardinality(cardinality) => map(&mut cardinality.field),
+            AggregationType::ValueCount(value_count) => map(&mut value_count.field),
+            AggregationType::PercentileRanks(percentile_ranks) => map(&mut percentile_ranks.field),
+            AggregationType::TopMetrics(top_metrics) => {
+                top_metrics.metrics.iter_mut().for_each(map);
+                map_sort_fields(&mut top_metrics.sort, f);
+            }
+            AggregationType::AdjacencyMatrix(adjacency_matrix) => {
+                for filter in adjacency_matrix.filters.values_mut() {
+                    filter.map_fields_in_place(f);
+                }
+                map_agg_fields(&mut adjacency_matrix.sub_aggs, f);
+            }
+            AggregationType::SignificantTerms(significant_terms) => {
+                map(&mut significant_terms.field);
+                map_agg_fields(&mut significant_terms.sub_aggs, f);
+            }
+            AggregationType::Sampler(sampler) => map_agg_fields(&mut sampler.sub_aggs, f),
+            AggregationType::TopHits(top_hits) => map_sort_fields(&mut top_hits.sort, f),
+            AggregationType::Global(global) => map_agg_fields(&mut global.sub_aggs, f),
+            AggregationType::GeoDistance(geo_distance) => {
+                map(&mut geo_distance.field);
+                map_agg_fields(&mut geo_distance.sub_aggs, f);
+            }
+            AggregationType::GeohashGrid(geohash_grid) => {
+                map(&mut geohash_grid.field);
+                map_agg_fields(&mut geohash_grid.sub_aggs, f);
+            }
+            AggregationType::GeoCentroid(geo_centroid) => map(&mut geo_centroid.field),
+            AggregationType::AutoDateHistogram(auto_date_histogram) => {
+                map(&mut auto_date_histogram.field);
+                map_agg_fields(&mut auto_date_histogram.sub_aggs, f);
+            }
+            AggregationType::Sum(sum) => map(&mut sum.field),
+            AggregationType::DateHistogram(date_histogram) => {
+                map(&mut date_histogram.field);
+                map_agg_fields(&mut date_histogram.sub_aggs, f);
+            }
+            AggregationType::Filters(filters) => {
+                match filters.filters {
+                    AggregationFilters::Named(ref mut named) => named
+                        .values_mut()
+                        .for_each(|filter| filter.map_fields_in_place(f)),
+                    AggregationFilters::Anonymous(ref mut anonymous) => anonymous
+                        .iter_mut()
+                        .for_each(|filter| filter.map_fields_in_place(f)),
+                }
+                map_agg_fields(&mut filters.sub_aggs, f);
+            }
+            // Pipeline aggregations reference other aggregations by name, not fields
+            AggregationType::CumulativeSum(_)
+            | AggregationType::Derivative(_)
+            | AggregationType::MovingFn(_)
+            | AggregationType::BucketSelector(_)
+            | AggregationType::BucketSort(_) => {}
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{SearchRequest, ToOpenSearchJson};
+
+/// The header line of a search in a multi-search request
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MsearchHeader<'a> {
+    /// The index to search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub index: Option<Cow<'a, str>>,
+    /// The nodes or shards to prefer for the search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub preference: Option<Cow<'a, str>>,
+}
+
+impl<'a> MsearchHeader<'a> {
+    /// Create a new empty MsearchHeader
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index to search
+    pub fn index(mut self, index: impl Into<Cow<'a, str>>) -> Self {
+        self.index = Some(index.into());
+        self
+    }
+
+    /// Set the nodes or shards to prefer for the search
+    pub fn preference(mut self, preference: impl Into<Cow<'a, str>>) -> Self {
+        self.preference = Some(preference.into());
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for MsearchHeader<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        if let Some(ref index) = self.index {
+            result.insert("index".to_string(), Value::String(index.to_string()));
+        }
+        if let Some(ref preference) = self.preference {
+            result.insert(
+                "preference".to_string(),
+                Value::String(preference.to_string()),
+            );
+        }
+        Value::Object(result)
+    }
+}
+
+/// The body of a `_msearch` request, a list of searches each with an optional header
+#[derive(Debug, Clone, Default)]
+pub struct MultiSearch<'a> {
+    /// The searches to run
+    pub searches: Vec<(Option<MsearchHeader<'a>>, SearchRequest<'a>)>,
+}
+
+impl<'a> MultiSearch<'a> {
+    /// Create a new empty MultiSearch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a search with a header
+    pub fn search(mut self, header: MsearchHeader<'a>, request: SearchRequest<'a>) -> Self {
+        self.searches.push((Some(header), request));
+        self
+    }
+
+    /// Add a search without a header, it runs against the index in the request path
+    pub fn search_without_header(mut self, request: SearchRequest<'a>) -> Self {
+        self.searches.push((None, request));
+        self
+    }
+
+    /// Render the newline-delimited header and body lines, including the trailing newline
+    /// OpenSearch requires
+    pub fn to_ndjson(&self) -> String {
+        let mut ndjson = String::new();
+        for (header, request) in &self.searches {
+            let header = header
+                .as_ref()
+                .map(|h| h.to_json())
+                .unwrap_or_else(|| Value::Object(Map::new()));
+            ndjson.push_str(&header.to_string());
+            ndjson.push('\n');
+            ndjson.push_str(&request.to_json().to_string());
+            ndjson.push('\n');
+        }
+        ndjson
+    }
+}
@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Point in time
+#[derive(Debug, Clone, Serialize)]
+pub struct Pit<'a> {
+    /// The point in time id
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    /// How long to extend the point in time for, e.g. `1m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_alive: Option<Cow<'a, str>>,
+}
+
+impl<'a> Pit<'a> {
+    /// Create a new Pit
+    pub fn new(id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            keep_alive: None,
+        }
+    }
+
+    /// Set how long to extend the point in time for
+    pub fn keep_alive(mut self, keep_alive: impl Into<Cow<'a, str>>) -> Self {
+        self.keep_alive = Some(keep_alive.into());
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for Pit<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("id".to_string(), Value::String(self.id.to_string()));
+        if let Some(ref keep_alive) = self.keep_alive {
+            result.insert(
+                "keep_alive".to_string(),
+                Value::String(keep_alive.to_string()),
+            );
+        }
+        Value::Object(result)
+    }
+}
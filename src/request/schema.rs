@@ -0,0 +1,150 @@
+use serde_json::{Value, json};
+
+use crate::SearchRequest;
+
+impl<'a> SearchRequest<'a> {
+    /// JSON Schema describing the request body produced by `to_json`.
+    ///
+    /// The schema describes the OpenSearch output shape, not the serde representation of the
+    /// Rust types. It is intentionally loose for queries and aggregations, which are validated by
+    /// OpenSearch itself, and only pins down the top-level structure of the request.
+    ///
+    /// It is written by hand rather than derived with schemars, as a derived schema would
+    /// describe the serde representation. Tests check it lists every top-level key and query
+    /// kind `to_json` can emit.
+    pub fn json_schema() -> Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "SearchRequest",
+            "description": "OpenSearch search request body",
+            "type": "object",
+            "properties": {
+                "query": query_schema(),
+                "size": { "type": "integer", "minimum": 0 },
+                "from": { "type": "integer", "minimum": 0 },
+                "sort": {
+                    "type": "array",
+                    "items": {
+                        "oneOf": [
+                            { "type": "string" },
+                            { "type": "object", "minProperties": 1, "maxProperties": 1 }
+                        ]
+                    }
+                },
+                "aggs": {
+                    "type": "object",
+                    "additionalProperties": { "type": "object", "minProperties": 1 }
+                },
+                "_source": { "type": "array", "items": { "type": "string" } },
+                "fields": {
+                    "type": "array",
+                    "items": {
+                        "oneOf": [
+                            { "type": "string" },
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "field": { "type": "string" },
+                                    "format": { "type": "string" }
+                                },
+                                "required": ["field"]
+                            }
+                        ]
+                    }
+                },
+                "highlight": {
+                    "type": "object",
+                    "properties": {
+                        "fields": {
+                            "type": "object",
+                            "additionalProperties": {
+                                "type": "object",
+                                "properties": {
+                                    "type": { "type": "string" },
+                                    "number_of_fragments": { "type": "integer", "minimum": 0 },
+                                    "pre_tags": { "type": "array", "items": { "type": "string" } },
+                                    "post_tags": { "type": "array", "items": { "type": "string" } }
+                                }
+                            }
+                        },
+                        "require_field_match": { "type": "boolean" }
+                    }
+                },
+                "track_total_hits": { "type": "boolean" },
+                "collapse": {
+                    "type": "object",
+                    "properties": { "field": { "type": "string" } },
+                    "required": ["field"]
+                },
+                "search_after": { "type": "array" },
+                "pit": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string" },
+                        "keep_alive": { "type": "string" }
+                    },
+                    "required": ["id"]
+                },
+                "slice": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "integer", "minimum": 0 },
+                        "max": { "type": "integer", "minimum": 1 }
+                    },
+                    "required": ["id", "max"]
+                },
+                "allow_partial_search_results": { "type": "boolean" },
+                "suggest": {
+                    "type": "object",
+                    "properties": { "text": { "type": "string" } },
+                    "additionalProperties": { "type": "object" }
+                },
+                "runtime_mappings": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "object",
+                        "properties": { "type": { "type": "string" } },
+                        "required": ["type"]
+                    }
+                }
+            }
+        })
+    }
+}
+
+fn query_schema() -> Value {
+    let query_kinds = [
+        "bool",
+        "constant_score",
+        "dis_max",
+        "function_score",
+        "geo_distance",
+        "has_child",
+        "has_parent",
+        "match",
+        "match_all",
+        "match_none",
+        "match_phrase",
+        "match_phrase_prefix",
+        "multi_match",
+        "nested",
+        "query_string",
+        "range",
+        "regexp",
+        "term",
+        "terms",
+        "wildcard",
+    ];
+
+    let properties: serde_json::Map<String, Value> = query_kinds
+        .iter()
+        .map(|name| (name.to_string(), json!({ "type": "object" })))
+        .collect();
+
+    json!({
+        "type": "object",
+        "minProperties": 1,
+        "maxProperties": 1,
+        "properties": properties
+    })
+}
@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use serde_json::{Map, Value};
+
+use crate::{SearchRequest, ToOpenSearchJson};
+
+/// A SearchRequest turned into a mustache search template.
+///
+/// Leaf values of the request are designated by a JSON pointer into its JSON and replaced with
+/// `{{param}}` placeholders. String values keep their quotes, any other value is emitted as a bare
+/// placeholder so the rendered value keeps its type.
+#[derive(Debug, Clone)]
+pub struct SearchTemplate<'a> {
+    request: SearchRequest<'a>,
+    params: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> SearchTemplate<'a> {
+    /// Wrap a SearchRequest
+    pub fn new(request: SearchRequest<'a>) -> Self {
+        Self {
+            request,
+            params: Vec::new(),
+        }
+    }
+
+    /// Replace the value at `pointer` (e.g. `/query/term/status`) with the `{{name}}` placeholder
+    pub fn param(
+        mut self,
+        pointer: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.params.push((pointer.into(), name.into()));
+        self
+    }
+
+    /// The wrapped request
+    pub fn request(&self) -> &SearchRequest<'a> {
+        &self.request
+    }
+
+    /// Render the mustache `source` string and the names of its params
+    pub fn to_template(&self) -> Result<(String, Vec<String>), SearchTemplateError> {
+        let mut json = self.request.to_json();
+
+        // Non-string values are first replaced with a sentinel string, which is unquoted into
+        // the placeholder once serialized. The sentinels use a prefix found nowhere in the
+        // request or the param names, so only substituted values are unquoted
+        let original = json.to_string();
+        let prefix = (0..)
+            .map(|nonce| format!("__search_template_{nonce}_"))
+            .find(|prefix| {
+                !original.contains(prefix.as_str())
+                    && !self
+                        .params
+                        .iter()
+                        .any(|(_, name)| name.contains(prefix.as_str()))
+            })
+            .unwrap_or_default();
+
+        let mut bare = Vec::new();
+        let mut names = Vec::new();
+        for (pointer, name) in &self.params {
+            let value =
+                json.pointer_mut(pointer)
+                    .ok_or_else(|| SearchTemplateError::UnknownPointer {
+                        pointer: pointer.to_string(),
+                    })?;
+            let placeholder = format!("{{{{{name}}}}}");
+            if value.is_string() {
+                *value = Value::String(placeholder);
+            } else {
+                let sentinel = format!("{prefix}{}__", bare.len());
+                *value = Value::String(sentinel.clone());
+                bare.push((sentinel, placeholder));
+            }
+            names.push(name.to_string());
+        }
+
+        let mut source = json.to_string();
+        for (sentinel, placeholder) in bare {
+            source = source.replace(&format!("\"{sentinel}\""), &placeholder);
+        }
+
+        Ok((source, names))
+    }
+
+    /// The body used to store the template as a script
+    pub fn to_script(&self) -> Result<Value, SearchTemplateError> {
+        let (source, _) = self.to_template()?;
+
+        let mut script_obj = Map::new();
+        script_obj.insert("lang".to_string(), Value::String("mustache".to_string()));
+        script_obj.insert("source".to_string(), Value::String(source));
+
+        let mut result = Map::new();
+        result.insert("script".to_string(), Value::Object(script_obj));
+        Ok(Value::Object(result))
+    }
+}
+
+impl<'a> From<SearchRequest<'a>> for SearchTemplate<'a> {
+    fn from(request: SearchRequest<'a>) -> Self {
+        Self::new(request)
+    }
+}
+
+/// A problem rendering a SearchTemplate
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTemplateError {
+    /// A param's JSON pointer doesn't resolve to a value in the request
+    UnknownPointer {
+        /// The unresolved pointer
+        pointer: String,
+    },
+}
+
+impl Display for SearchTemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchTemplateError::UnknownPointer { pointer } => {
+                write!(
+                    f,
+                    "search template pointer {pointer:?} not found in the request"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SearchTemplateError {}
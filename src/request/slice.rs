@@ -0,0 +1,29 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+/// Slice
+#[derive(Debug, Clone, Serialize)]
+pub struct Slice {
+    /// The id of this slice
+    pub id: u32,
+    /// The total number of slices
+    pub max: u32,
+}
+
+impl Slice {
+    /// Create a new Slice
+    pub fn new(id: u32, max: u32) -> Self {
+        Self { id, max }
+    }
+}
+
+impl ToOpenSearchJson for Slice {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("id".to_string(), Value::Number(self.id.into()));
+        result.insert("max".to_string(), Value::Number(self.max.into()));
+        Value::Object(result)
+    }
+}
@@ -5,13 +5,14 @@ use serde_json::{Map, Value};
 
 use crate::ToOpenSearchJson;
 
+mod geo_distance;
 mod script;
 
+pub use geo_distance::*;
 pub use script::*;
 
 /// Sort Order
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone)]
 pub enum SortOrder {
     /// Ascending
     Asc,
@@ -19,6 +20,23 @@ pub enum SortOrder {
     Desc,
 }
 
+impl SortOrder {
+    /// The order as sent to OpenSearch, `"asc"` or `"desc"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// Serializes to the same lowercase string as [`SortOrder::as_str`].
+impl Serialize for SortOrder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 /// Sort Mode
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -51,9 +69,14 @@ pub struct FieldSort<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(borrow)]
     pub unmapped_type: Option<Cow<'a, str>>,
+    /// How a multi-valued field is reduced to one sort value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<SortMode>,
 }
 
-/// Score sort with order
+/// Score sort with order.
+///
+/// Unlike [`FieldSort`] this has no mode, as every document has exactly one score.
 #[derive(Debug, Clone, Serialize)]
 pub struct ScoreWithOrderSort {
     /// Sort order
@@ -68,6 +91,7 @@ impl<'a> FieldSort<'a> {
             order,
             missing: None,
             unmapped_type: None,
+            mode: None,
         }
     }
 
@@ -82,6 +106,13 @@ impl<'a> FieldSort<'a> {
         self.unmapped_type = Some(unmapped_type.into());
         self
     }
+
+    /// Set how a multi-valued field is reduced to one sort value. Only meaningful for fields
+    /// holding several values per document, a single-valued field sorts the same in every mode.
+    pub fn mode(mut self, mode: SortMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
 }
 
 impl ScoreWithOrderSort {
@@ -96,23 +127,17 @@ impl<'a> ToOpenSearchJson for FieldSort<'a> {
         let mut result = Map::new();
 
         // Use simplified format when there are no additional parameters
-        if self.missing.is_none() && self.unmapped_type.is_none() {
+        if self.missing.is_none() && self.unmapped_type.is_none() && self.mode.is_none() {
             result.insert(
                 self.field.to_string(),
-                Value::String(match self.order {
-                    SortOrder::Asc => "asc".to_string(),
-                    SortOrder::Desc => "desc".to_string(),
-                }),
+                Value::String(self.order.as_str().to_string()),
             );
         } else {
             // Use object format when there are additional parameters
             let mut field_obj = Map::new();
             field_obj.insert(
                 "order".to_string(),
-                Value::String(match self.order {
-                    SortOrder::Asc => "asc".to_string(),
-                    SortOrder::Desc => "desc".to_string(),
-                }),
+                Value::String(self.order.as_str().to_string()),
             );
 
             if let Some(ref missing) = self.missing {
@@ -126,6 +151,13 @@ impl<'a> ToOpenSearchJson for FieldSort<'a> {
                 );
             }
 
+            if let Some(ref mode) = self.mode {
+                field_obj.insert(
+                    "mode".to_string(),
+                    serde_json::to_value(mode).expect("Failed to serialize mode"),
+                );
+            }
+
             result.insert(self.field.to_string(), Value::Object(field_obj));
         }
 
@@ -138,38 +170,69 @@ impl ToOpenSearchJson for ScoreWithOrderSort {
         let mut result = Map::new();
         result.insert(
             "_score".to_string(),
-            Value::String(match self.order {
-                SortOrder::Asc => "asc".to_string(),
-                SortOrder::Desc => "desc".to_string(),
-            }),
+            Value::String(self.order.as_str().to_string()),
         );
         Value::Object(result)
     }
 }
 
 /// Sort Type
-#[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type", content = "params")]
+#[derive(Debug, Clone)]
 pub enum SortType<'a> {
     /// Field sort
     Field(FieldSort<'a>),
     /// Score sort
     Score,
+    /// Index order sort, the most efficient order when scrolling
+    Doc,
     /// Score with sort order
     ScoreWithOrder(ScoreWithOrderSort),
     /// Script sort
     ScriptSort(ScriptSort<'a>),
+    /// Geo distance sort
+    GeoDistance(GeoDistanceSort<'a>),
 }
+impl<'a> SortType<'a> {
+    /// Convenience method for sorting on a field in ascending order
+    pub fn field_asc(field: impl Into<Cow<'a, str>>) -> Self {
+        SortType::Field(FieldSort::new(field, SortOrder::Asc))
+    }
+
+    /// Convenience method for sorting on a field in descending order
+    pub fn field_desc(field: impl Into<Cow<'a, str>>) -> Self {
+        SortType::Field(FieldSort::new(field, SortOrder::Desc))
+    }
+
+    /// Convenience method for sorting by score, highest first
+    pub fn score_desc() -> Self {
+        SortType::ScoreWithOrder(ScoreWithOrderSort::new(SortOrder::Desc))
+    }
+
+    /// Convenience method for sorting in index order, the cheapest sort for scroll and PIT exports
+    pub fn doc() -> Self {
+        SortType::Doc
+    }
+}
+
 impl<'a> ToOpenSearchJson for SortType<'a> {
     fn to_json(&self) -> Value {
         match self {
             SortType::Field(field_sort) => field_sort.to_json(),
             SortType::Score => serde_json::json!("_score"),
+            SortType::Doc => serde_json::json!("_doc"),
             SortType::ScoreWithOrder(score_sort) => score_sort.to_json(),
             SortType::ScriptSort(script_sort) => script_sort.to_json(),
+            SortType::GeoDistance(geo_distance) => geo_distance.to_json(),
         }
     }
 }
 
+/// Serializes to the same OpenSearch JSON as [`ToOpenSearchJson::to_json`].
+impl<'a> Serialize for SortType<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod test;
@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{GeoPoint, SortOrder, ToOpenSearchJson};
+
+/// Geo Distance Sort
+#[derive(Debug, Clone, Serialize)]
+pub struct GeoDistanceSort<'a> {
+    /// The geo point field to sort on
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The point to measure the distance from
+    pub point: GeoPoint,
+    /// Sort order
+    pub order: SortOrder,
+    /// The unit the sort values are returned in, e.g. `km`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub unit: Option<Cow<'a, str>>,
+    /// Whether to treat an unmapped field as having no values instead of failing the sort
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_unmapped: Option<bool>,
+}
+
+impl<'a> GeoDistanceSort<'a> {
+    /// Create a new GeoDistanceSort
+    pub fn new(field: impl Into<Cow<'a, str>>, point: GeoPoint, order: SortOrder) -> Self {
+        Self {
+            field: field.into(),
+            point,
+            order,
+            unit: None,
+            ignore_unmapped: None,
+        }
+    }
+
+    /// Set the unit, either a [`crate::DistanceUnit`] or a string
+    pub fn unit(mut self, unit: impl Into<Cow<'a, str>>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Set whether to treat an unmapped field as having no values instead of failing the sort
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for GeoDistanceSort<'a> {
+    fn to_json(&self) -> Value {
+        let mut geo_distance_obj = Map::new();
+        geo_distance_obj.insert(self.field.to_string(), self.point.to_json());
+        geo_distance_obj.insert(
+            "order".to_string(),
+            Value::String(self.order.as_str().to_string()),
+        );
+
+        if let Some(ref unit) = self.unit {
+            geo_distance_obj.insert("unit".to_string(), Value::String(unit.to_string()));
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped {
+            geo_distance_obj.insert("ignore_unmapped".to_string(), Value::Bool(ignore_unmapped));
+        }
+
+        let mut result = Map::new();
+        result.insert("_geo_distance".to_string(), Value::Object(geo_distance_obj));
+        Value::Object(result)
+    }
+}
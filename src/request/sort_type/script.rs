@@ -30,7 +30,18 @@ pub enum Lang {
     Mustache,
 }
 
-/// Script
+impl Lang {
+    /// The language as sent to OpenSearch
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Lang::Painless => "painless",
+            Lang::Expression => "expression",
+            Lang::Mustache => "mustache",
+        }
+    }
+}
+
+/// Script, shared by script sorts and script score functions
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct Script<'a> {
     /// The script
@@ -63,6 +74,30 @@ impl<'a> Script<'a> {
         self.params = Some(params);
         self
     }
+
+    /// Convert to an owned version with 'static lifetime
+    pub fn to_owned(&self) -> Script<'static> {
+        Script {
+            source: Cow::Owned(self.source.to_string()),
+            lang: self.lang.clone(),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl<'a> ToOpenSearchJson for Script<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("source".to_string(), Value::String(self.source.to_string()));
+        result.insert(
+            "lang".to_string(),
+            Value::String(self.lang.as_str().to_string()),
+        );
+        if let Some(ref params) = self.params {
+            result.insert("params".to_string(), params.clone());
+        }
+        Value::Object(result)
+    }
 }
 
 /// Script Sort
@@ -115,18 +150,12 @@ impl<'a> ToOpenSearchJson for ScriptSort<'a> {
         );
 
         // Add script
-        script_obj.insert(
-            "script".to_string(),
-            serde_json::to_value(&self.script).expect("Failed to serialize script"),
-        );
+        script_obj.insert("script".to_string(), self.script.to_json());
 
         // Add order
         script_obj.insert(
             "order".to_string(),
-            Value::String(match self.order {
-                SortOrder::Asc => "asc".to_string(),
-                SortOrder::Desc => "desc".to_string(),
-            }),
+            Value::String(self.order.as_str().to_string()),
         );
 
         // Add mode if present
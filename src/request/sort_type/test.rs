@@ -148,3 +148,127 @@ fn test_field_sort_with_cow_str() {
     assert_eq!(result1, serde_json::json!({"field1": "asc"}));
     assert_eq!(result2, serde_json::json!({"field2": "desc"}));
 }
+
+#[test]
+fn test_doc_sort() {
+    assert_eq!(SortType::Doc.to_json(), serde_json::json!("_doc"));
+}
+
+#[test]
+fn test_mixed_sort_array() {
+    // Bare-string sorts and object sorts can be mixed in the same sort array
+    let request = crate::SearchRequest::new()
+        .sort(SortType::Score)
+        .sort(SortType::Field(FieldSort::new("field", SortOrder::Asc)))
+        .sort(SortType::Doc);
+
+    let expected = serde_json::json!({
+        "sort": ["_score", {"field": "asc"}, "_doc"]
+    });
+
+    assert_eq!(request.to_json(), expected);
+
+    // The sorts keep the order they were added in
+    let reversed = crate::SearchRequest::new()
+        .sort(SortType::Doc)
+        .sort(SortType::Field(FieldSort::new("field", SortOrder::Asc)))
+        .sort(SortType::Score);
+    assert_eq!(
+        reversed.to_json()["sort"],
+        serde_json::json!(["_doc", {"field": "asc"}, "_score"])
+    );
+}
+
+#[test]
+fn test_serialize_matches_to_json() {
+    assert_eq!(
+        serde_json::to_value(&SortType::Score).unwrap(),
+        serde_json::json!("_score")
+    );
+    let sort = SortType::Field(FieldSort::new("timestamp", SortOrder::Desc));
+    assert_eq!(serde_json::to_value(&sort).unwrap(), sort.to_json());
+}
+
+#[test]
+fn test_geo_distance_sort() {
+    let sort = GeoDistanceSort::new(
+        "location",
+        crate::GeoPoint::new(40.7, -74.0),
+        SortOrder::Asc,
+    )
+    .unit(crate::DistanceUnit::Kilometers)
+    .ignore_unmapped(true);
+
+    assert_eq!(
+        sort.to_json(),
+        serde_json::json!({
+            "_geo_distance": {
+                "location": {"lat": 40.7, "lon": -74.0},
+                "order": "asc",
+                "unit": "km",
+                "ignore_unmapped": true
+            }
+        })
+    );
+}
+
+#[test]
+fn test_sort_shorthands() {
+    assert_eq!(
+        SortType::field_desc("date").to_json(),
+        serde_json::json!({"date": "desc"})
+    );
+    assert_eq!(
+        SortType::field_asc("price").to_json(),
+        serde_json::json!({"price": "asc"})
+    );
+    assert_eq!(
+        SortType::score_desc().to_json(),
+        serde_json::json!({"_score": "desc"})
+    );
+}
+
+#[test]
+fn test_sort_order_lowercase() {
+    for (order, expected) in [(SortOrder::Asc, "asc"), (SortOrder::Desc, "desc")] {
+        assert_eq!(
+            SortType::Field(FieldSort::new("timestamp", order.clone())).to_json(),
+            serde_json::json!({ "timestamp": expected })
+        );
+        assert_eq!(
+            SortType::ScoreWithOrder(ScoreWithOrderSort::new(order.clone())).to_json(),
+            serde_json::json!({ "_score": expected })
+        );
+        assert_eq!(serde_json::to_value(order).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_field_sort_with_mode() {
+    let sort = FieldSort::new("prices", SortOrder::Asc).mode(SortMode::Min);
+
+    assert_eq!(
+        sort.to_json(),
+        serde_json::json!({
+            "prices": {
+                "order": "asc",
+                "mode": "min"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_score_sort_never_emits_mode() {
+    for sort in [
+        SortType::Score,
+        SortType::score_desc(),
+        SortType::ScoreWithOrder(ScoreWithOrderSort::new(SortOrder::Asc)),
+    ] {
+        let json = sort.to_json();
+        assert!(
+            !json.to_string().contains("mode"),
+            "unexpected mode in {json}"
+        );
+    }
+}
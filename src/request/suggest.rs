@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::ToOpenSearchJson;
+
+mod phrase;
+
+pub use phrase::*;
+
+/// Suggester Type
+#[derive(Debug, Clone)]
+pub enum SuggesterType<'a> {
+    /// Phrase suggester
+    Phrase(PhraseSuggester<'a>),
+}
+
+impl<'a> ToOpenSearchJson for SuggesterType<'a> {
+    fn to_json(&self) -> Value {
+        match self {
+            SuggesterType::Phrase(phrase) => phrase.to_json(),
+        }
+    }
+}
+
+/// Serializes to the same OpenSearch JSON as [`ToOpenSearchJson::to_json`].
+impl<'a> Serialize for SuggesterType<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+/// The `suggest` section of a search request, a set of named suggesters
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Suggest<'a> {
+    /// Text shared by every suggester that doesn't set its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub text: Option<Cow<'a, str>>,
+    /// The named suggesters
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub suggesters: HashMap<Cow<'a, str>, SuggesterType<'a>>,
+}
+
+impl<'a> Suggest<'a> {
+    /// Create a new empty Suggest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text shared by every suggester that doesn't set its own
+    pub fn text(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Add a named suggester
+    pub fn suggester(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        suggester: SuggesterType<'a>,
+    ) -> Self {
+        self.suggesters.insert(name.into(), suggester);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for Suggest<'a> {
+    fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        if let Some(ref text) = self.text {
+            result.insert("text".to_string(), Value::String(text.to_string()));
+        }
+        for (name, suggester) in &self.suggesters {
+            result.insert(name.to_string(), suggester.to_json());
+        }
+        Value::Object(result)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::util::finite;
+use crate::{QueryType, SuggesterType, ToOpenSearchJson};
+
+/// Checks each phrase suggestion against the index by running a query with it
+#[derive(Debug, Clone, Serialize)]
+pub struct Collate<'a> {
+    /// The query template, `{{suggestion}}` is replaced with each suggestion
+    #[serde(borrow)]
+    pub query: QueryType<'a>,
+    /// Whether to return every suggestion with a `collate_match` flag instead of dropping the
+    /// suggestions that don't match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prune: Option<bool>,
+}
+
+impl<'a> Collate<'a> {
+    /// Create a new Collate with a query template
+    pub fn new(query: QueryType<'a>) -> Self {
+        Self { query, prune: None }
+    }
+
+    /// Set whether to keep non-matching suggestions, flagged with `collate_match`
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = Some(prune);
+        self
+    }
+}
+
+impl<'a> ToOpenSearchJson for Collate<'a> {
+    fn to_json(&self) -> Value {
+        let mut query_obj = Map::new();
+        query_obj.insert("source".to_string(), self.query.to_json());
+
+        let mut result = Map::new();
+        result.insert("query".to_string(), Value::Object(query_obj));
+        if let Some(prune) = self.prune {
+            result.insert("prune".to_string(), Value::Bool(prune));
+        }
+        Value::Object(result)
+    }
+}
+
+/// Phrase Suggester
+#[derive(Debug, Clone, Serialize)]
+pub struct PhraseSuggester<'a> {
+    /// The field to build suggestions from
+    #[serde(borrow)]
+    pub field: Cow<'a, str>,
+    /// The text to suggest for, overriding the text shared by the suggest section
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub text: Option<Cow<'a, str>>,
+    /// The size of the n-grams in the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gram_size: Option<u32>,
+    /// The maximum number or fraction of terms considered misspellings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_errors: Option<f64>,
+    /// How much more likely than the input a suggestion must be to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Check suggestions against the index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(borrow)]
+    pub collate: Option<Collate<'a>>,
+}
+
+impl<'a> PhraseSuggester<'a> {
+    /// Create a new PhraseSuggester for the given field
+    pub fn new(field: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            field: field.into(),
+            text: None,
+            gram_size: None,
+            max_errors: None,
+            confidence: None,
+            collate: None,
+        }
+    }
+
+    /// Set the text to suggest for
+    pub fn text(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Set the size of the n-grams in the field
+    pub fn gram_size(mut self, gram_size: u32) -> Self {
+        self.gram_size = Some(gram_size);
+        self
+    }
+
+    /// Set the maximum number or fraction of terms considered misspellings
+    pub fn max_errors(mut self, max_errors: f64) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// Set how much more likely than the input a suggestion must be to be returned
+    pub fn confidence(mut self, confidence: f64) -> Self {
+        self.confidence = Some(confidence);
+        self
+    }
+
+    /// Check suggestions against the index
+    pub fn collate(mut self, collate: Collate<'a>) -> Self {
+        self.collate = Some(collate);
+        self
+    }
+}
+
+impl<'a> From<PhraseSuggester<'a>> for SuggesterType<'a> {
+    fn from(phrase_suggester: PhraseSuggester<'a>) -> Self {
+        SuggesterType::Phrase(phrase_suggester)
+    }
+}
+
+impl<'a> ToOpenSearchJson for PhraseSuggester<'a> {
+    fn to_json(&self) -> Value {
+        let mut phrase_obj = Map::new();
+        phrase_obj.insert("field".to_string(), Value::String(self.field.to_string()));
+
+        if let Some(gram_size) = self.gram_size {
+            phrase_obj.insert("gram_size".to_string(), Value::Number(gram_size.into()));
+        }
+        if let Some(max_errors) = self.max_errors.and_then(finite) {
+            phrase_obj.insert("max_errors".to_string(), max_errors.into());
+        }
+        if let Some(confidence) = self.confidence.and_then(finite) {
+            phrase_obj.insert("confidence".to_string(), confidence.into());
+        }
+        if let Some(ref collate) = self.collate {
+            phrase_obj.insert("collate".to_string(), collate.to_json());
+        }
+
+        let mut result = Map::new();
+        if let Some(ref text) = self.text {
+            result.insert("text".to_string(), Value::String(text.to_string()));
+        }
+        result.insert("phrase".to_string(), Value::Object(phrase_obj));
+        Value::Object(result)
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn test_phrase_suggester_with_collate() {
+    let suggest = Suggest::new().text("noble prize").suggester(
+        "simple_phrase",
+        PhraseSuggester::new("title.trigram")
+            .gram_size(3)
+            .max_errors(2.0)
+            .confidence(0.5)
+            .collate(
+                Collate::new(crate::QueryType::match_phrase("title", "{{suggestion}}")).prune(true),
+            )
+            .into(),
+    );
+
+    assert_eq!(
+        suggest.to_json(),
+        serde_json::json!({
+            "text": "noble prize",
+            "simple_phrase": {
+                "phrase": {
+                    "field": "title.trigram",
+                    "gram_size": 3,
+                    "max_errors": 2.0,
+                    "confidence": 0.5,
+                    "collate": {
+                        "query": {
+                            "source": {
+                                "match_phrase": {"title": "{{suggestion}}"}
+                            }
+                        },
+                        "prune": true
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_phrase_suggester_own_text() {
+    let suggester = PhraseSuggester::new("title").text("quikc fox");
+
+    assert_eq!(
+        suggester.to_json(),
+        serde_json::json!({
+            "text": "quikc fox",
+            "phrase": {"field": "title"}
+        })
+    );
+}
@@ -0,0 +1,822 @@
+use super::*;
+use crate::TermQuery;
+
+#[test]
+fn test_cached_request() {
+    let mut request = SearchRequest::new()
+        .query(QueryType::term("status", "published"))
+        .size(10);
+    let expected = request.to_json();
+
+    let cached = CachedRequest::new(request.clone());
+    request = request.size(50).from(10);
+
+    assert_ne!(request.to_json(), expected);
+    assert_eq!(cached.as_json(), &expected);
+    assert_eq!(cached.to_json(), cached.request().to_json());
+    assert!(std::ptr::eq(cached.as_json(), cached.as_json()));
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema() {
+    let schema = SearchRequest::json_schema();
+
+    assert_eq!(schema["type"], "object");
+    assert!(schema["properties"].is_object());
+    assert!(schema["properties"]["query"]["properties"]["bool"].is_object());
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema_lists_every_top_level_key() {
+    // An exhaustive struct literal, so a new SearchRequest field fails to compile here until it
+    // is set, and then fails the assertion until the schema lists it
+    let request = SearchRequest {
+        query: Some(QueryType::match_all()),
+        size: Some(10),
+        from: Some(0),
+        sort: Cow::Owned(vec![SortType::Score]),
+        aggs: HashMap::from([(
+            Cow::Borrowed("tags"),
+            AggregationType::Terms(TermsAggregation::new("tags")),
+        )]),
+        _source: Cow::Owned(vec![Cow::Borrowed("title")]),
+        fields: Cow::Owned(vec![FieldAndFormat::new("published_at")]),
+        highlight: Some(Highlight::new().field("title", HighlightField::new())),
+        track_total_hits: Some(true),
+        collapse: Some(Collapse::new("user_id")),
+        search_after: Cow::Owned(vec![Value::from(1)]),
+        pit: Some(Pit::new("pit-id")),
+        slice: Some(Slice::new(0, 2)),
+        allow_partial_search_results: Some(false),
+        suggest: Some(Suggest::new().text("fox")),
+        runtime_mappings: Some(serde_json::Map::new()),
+    };
+    let schema = SearchRequest::json_schema();
+
+    for key in request.to_json().as_object().unwrap().keys() {
+        assert!(
+            schema["properties"][key].is_object(),
+            "{key} is missing from the schema properties"
+        );
+    }
+}
+
+#[test]
+fn test_builder_from_request() {
+    let base = SearchRequest::new()
+        .query(QueryType::term("tenant", "acme"))
+        .size(10)
+        .source_fields(["title"]);
+
+    let mut builder = SearchRequestBuilder::from_request(&base);
+    builder.size(50);
+    let derived = builder.build();
+
+    assert_eq!(
+        derived.to_json(),
+        serde_json::json!({
+            "query": {"term": {"tenant": "acme"}},
+            "size": 50,
+            "_source": ["title"]
+        })
+    );
+    assert_eq!(base.size, Some(10));
+}
+
+#[test]
+fn test_builder_reset() {
+    let mut builder = SearchRequestBuilder::new();
+    builder
+        .query(QueryType::term("tenant", "acme"))
+        .size(10)
+        .add_source_field("title");
+
+    builder.reset();
+
+    assert_eq!(builder.build().to_json(), serde_json::json!({}));
+}
+
+#[test]
+fn test_add_filter_to_bool_query() {
+    let request = SearchRequest::new()
+        .query(BoolQuery::new().must(QueryType::term("a", "a")).into())
+        .add_filter(QueryType::term("tenant", "acme"));
+
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": {
+                "bool": {
+                    "must": [{"term": {"a": "a"}}],
+                    "filter": [{"term": {"tenant": "acme"}}]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_add_filter_keeps_should_clauses_required() {
+    let should_only = || {
+        BoolQuery::new()
+            .should(QueryType::term("a", "a"))
+            .should(QueryType::term("b", "b"))
+    };
+
+    let request = SearchRequest::new()
+        .query(should_only().into())
+        .add_filter(QueryType::term("tenant", "acme"));
+
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": {
+                "bool": {
+                    "should": [{"term": {"a": "a"}}, {"term": {"b": "b"}}],
+                    "filter": [{"term": {"tenant": "acme"}}],
+                    "minimum_should_match": 1
+                }
+            }
+        })
+    );
+
+    // An explicit minimum_should_match is kept
+    let request = SearchRequest::new()
+        .query(should_only().minimum_should_match(2).into())
+        .add_filter(QueryType::term("tenant", "acme"));
+    assert_eq!(
+        request.to_json()["query"]["bool"]["minimum_should_match"],
+        2
+    );
+}
+
+#[test]
+fn test_add_filter_wraps_other_query() {
+    let request = SearchRequest::new()
+        .query(QueryType::match_phrase("title", "rust"))
+        .add_filter(QueryType::term("tenant", "acme"));
+
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": {
+                "bool": {
+                    "must": [{"match_phrase": {"title": "rust"}}],
+                    "filter": [{"term": {"tenant": "acme"}}]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_add_filter_without_query() {
+    let mut builder = SearchRequestBuilder::new();
+    builder.add_filter(QueryType::term("tenant", "acme"));
+
+    assert_eq!(
+        builder.build().to_json(),
+        serde_json::json!({
+            "query": {
+                "bool": {
+                    "filter": [{"term": {"tenant": "acme"}}]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_sliced_pit_request() {
+    let mut builder = SearchRequestBuilder::new();
+    builder
+        .size(1000)
+        .pit(Pit::new("46ToAwMDaWR5BXV1aWQy").keep_alive("1m"))
+        .slice(Slice::new(0, 4))
+        .add_sort(SortType::Doc)
+        .add_search_after_value(serde_json::json!(1234));
+
+    assert_eq!(
+        builder.build().to_json(),
+        serde_json::json!({
+            "size": 1000,
+            "sort": ["_doc"],
+            "search_after": [1234],
+            "pit": {
+                "id": "46ToAwMDaWR5BXV1aWQy",
+                "keep_alive": "1m"
+            },
+            "slice": {
+                "id": 0,
+                "max": 4
+            }
+        })
+    );
+}
+
+#[test]
+fn test_search_template() {
+    let template = SearchTemplate::new(
+        SearchRequest::new()
+            .query(QueryType::term("status", "published"))
+            .size(10),
+    )
+    .param("/query/term/status", "status")
+    .param("/size", "size");
+
+    let (source, params) = template.to_template().unwrap();
+    assert_eq!(
+        source,
+        r#"{"query":{"term":{"status":"{{status}}"}},"size":{{size}}}"#
+    );
+    assert_eq!(params, ["status", "size"]);
+
+    assert_eq!(
+        template.to_script().unwrap(),
+        serde_json::json!({
+            "script": {
+                "lang": "mustache",
+                "source": source
+            }
+        })
+    );
+}
+
+#[test]
+fn test_search_template_only_unquotes_substituted_values() {
+    // The literal "{{size}}" string and the string param named size keep their quotes
+    let template = SearchTemplate::new(
+        SearchRequest::new()
+            .query(
+                BoolQuery::new()
+                    .must(QueryType::term("label", "{{size}}"))
+                    .filter(QueryType::term("status", "published"))
+                    .into(),
+            )
+            .size(10),
+    )
+    .param("/query/bool/filter/0/term/status", "size")
+    .param("/size", "size");
+
+    let (source, _) = template.to_template().unwrap();
+    assert_eq!(
+        source,
+        r#"{"query":{"bool":{"filter":[{"term":{"status":"{{size}}"}}],"must":[{"term":{"label":"{{size}}"}}]}},"size":{{size}}}"#
+    );
+}
+
+#[test]
+fn test_search_template_unknown_pointer() {
+    let template = SearchTemplate::new(SearchRequest::new().size(10)).param("/sise", "size");
+
+    assert_eq!(
+        template.to_template(),
+        Err(SearchTemplateError::UnknownPointer {
+            pointer: "/sise".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_multi_search_ndjson() {
+    let multi_search = MultiSearch::new()
+        .search(
+            MsearchHeader::new().index("products").preference("_local"),
+            SearchRequest::new().query(QueryType::term("status", "active")),
+        )
+        .search_without_header(SearchRequest::new().size(0));
+
+    let ndjson = multi_search.to_ndjson();
+    assert!(ndjson.ends_with('\n'));
+
+    let lines: Vec<Value> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            serde_json::json!({"index": "products", "preference": "_local"}),
+            serde_json::json!({"query": {"term": {"status": "active"}}}),
+            serde_json::json!({}),
+            serde_json::json!({"size": 0}),
+        ]
+    );
+}
+
+#[test]
+fn test_count_only() {
+    let request = SearchRequest::new()
+        .query(QueryType::term("status", "active"))
+        .size(25)
+        .sort(SortType::Score)
+        .source_fields(["title"])
+        .highlight(Highlight::new().field("title", HighlightField::new()))
+        .agg(
+            "categories",
+            AggregationType::Terms(TermsAggregation::new("category")),
+        )
+        .count_only();
+
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": {"term": {"status": "active"}},
+            "size": 0,
+            "aggs": {
+                "categories": {"terms": {"field": "category"}}
+            }
+        })
+    );
+}
+
+#[test]
+fn test_validate_deep_pagination() {
+    assert_eq!(SearchRequest::new().from(9_990).size(10).validate(), Ok(()));
+
+    assert_eq!(
+        SearchRequest::new().from(9_991).size(10).validate(),
+        Err(ValidationError::DeepPagination {
+            from: 9_991,
+            size: 10,
+            max_result_window: 10_000,
+        })
+    );
+
+    // Without a size OpenSearch returns 10 results
+    assert!(SearchRequest::new().from(9_995).validate().is_err());
+
+    let options = ValidationOptions::new().max_result_window(50_000);
+    assert_eq!(
+        SearchRequest::new()
+            .from(20_000)
+            .size(100)
+            .validate_with(&options),
+        Ok(())
+    );
+
+    let mut builder = SearchRequestBuilder::new();
+    builder.from(10_000).size(1);
+    assert!(builder.try_build().is_err());
+}
+
+#[test]
+fn test_scroll_request_sorted_by_doc() {
+    let request = SearchRequest::new()
+        .query(QueryType::match_all())
+        .size(1000)
+        .sort(SortType::doc());
+
+    assert_eq!(request.to_json()["sort"], serde_json::json!(["_doc"]));
+}
+
+#[test]
+fn test_paginate() {
+    let request = SearchRequest::new().paginate(2, 20);
+    assert_eq!(request.from, Some(40));
+    assert_eq!(request.size, Some(20));
+
+    let request = SearchRequest::new().paginate(u32::MAX, 20);
+    assert_eq!(request.from, Some(u32::MAX));
+
+    let mut builder = SearchRequestBuilder::new();
+    builder.paginate(3, 10);
+    let request = builder.build();
+    assert_eq!(request.from, Some(30));
+    assert_eq!(request.size, Some(10));
+}
+
+#[test]
+fn test_aggregations_only() {
+    let mut aggs = HashMap::new();
+    aggs.insert(
+        "unique_users".into(),
+        AggregationType::Cardinality(CardinalityAggregation::new("user_id")),
+    );
+
+    assert_eq!(
+        SearchRequest::aggregations_only(aggs).to_json(),
+        serde_json::json!({
+            "size": 0,
+            "aggs": {
+                "unique_users": {"cardinality": {"field": "user_id"}}
+            }
+        })
+    );
+}
+
+#[test]
+fn test_allow_partial_search_results() {
+    let request = SearchRequest::new()
+        .query(QueryType::match_all())
+        .allow_partial_search_results(false);
+    assert_eq!(
+        request.to_json(),
+        serde_json::json!({
+            "query": {"match_all": {}},
+            "allow_partial_search_results": false
+        })
+    );
+
+    let mut builder = SearchRequestBuilder::from_request(&request);
+    builder.allow_partial_search_results(true);
+    assert_eq!(
+        builder.build().to_json()["allow_partial_search_results"],
+        true
+    );
+}
+
+#[test]
+fn test_builder_dedup_source_fields_and_sorts() {
+    let mut builder = SearchRequestBuilder::new();
+    builder
+        .add_source_field_unique("title")
+        .add_source_field_unique("author")
+        .add_source_field_unique("title")
+        .add_sort(SortType::Field(FieldSort::new("date", SortOrder::Desc)))
+        .add_sort(SortType::Score)
+        .add_sort(SortType::Field(FieldSort::new("date", SortOrder::Desc)))
+        .add_sort(SortType::Field(FieldSort::new("date", SortOrder::Asc)))
+        .dedup_sorts();
+
+    let request = builder.build();
+    assert_eq!(request._source.as_ref(), ["title", "author"]);
+    assert_eq!(
+        request.to_json()["sort"],
+        serde_json::json!([{"date": "desc"}, "_score", {"date": "asc"}])
+    );
+}
+
+#[test]
+fn test_named_queries() {
+    let request = SearchRequest::new().query(QueryType::from(
+        BoolQuery::new()
+            .must(TermQuery::new("status", "active").name("active").into())
+            .should(QueryType::constant_score(
+                TermQuery::new("tier", "gold").name("gold_tier").into(),
+            ))
+            .filter(QueryType::term("type", "post")),
+    ));
+
+    assert_eq!(request.named_queries(), ["active", "gold_tier"]);
+    assert!(SearchRequest::new().named_queries().is_empty());
+}
+
+#[test]
+fn test_validate_geo_distance_sort_unit() {
+    let sort = |unit: &'static str| {
+        SortType::GeoDistance(
+            GeoDistanceSort::new(
+                "location",
+                crate::GeoPoint::new(40.7, -74.0),
+                SortOrder::Asc,
+            )
+            .unit(unit),
+        )
+    };
+
+    assert_eq!(SearchRequest::new().sort(sort("km")).validate(), Ok(()));
+    assert_eq!(SearchRequest::new().sort(sort("miles")).validate(), Ok(()));
+    assert_eq!(
+        SearchRequest::new().sort(sort("parsecs")).validate(),
+        Err(ValidationError::InvalidDistanceUnit {
+            field: "location".to_string(),
+            unit: "parsecs".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_highlight_type() {
+    assert_eq!(
+        serde_json::to_value(HighlightType::Fvh).unwrap(),
+        serde_json::json!("fvh")
+    );
+    assert_eq!(HighlightType::from("plain"), HighlightType::Plain);
+
+    let request = |highlight_type: &'static str| {
+        SearchRequest::new().highlight(Highlight::new().field(
+            "title",
+            HighlightField::new().highlight_type(highlight_type),
+        ))
+    };
+
+    assert_eq!(
+        request("unified").to_json()["highlight"]["fields"]["title"]["type"],
+        "unified"
+    );
+    assert_eq!(request("unified").validate(), Ok(()));
+    assert_eq!(
+        request("fancy").validate(),
+        Err(ValidationError::UnknownHighlightType {
+            field: "title".to_string(),
+            highlight_type: "fancy".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_validate_collapse_field() {
+    let options = ValidationOptions::new().nested_path("comments");
+
+    let request = SearchRequest::new()
+        .collapse(Collapse::new("user_id").inner_hits(InnerHits::new("latest").size(3)));
+    assert_eq!(request.validate(), Ok(()));
+    assert_eq!(request.validate_with(&options), Ok(()));
+
+    // Only fields under the declared path are rejected, not ones sharing its prefix
+    let request = SearchRequest::new().collapse(Collapse::new("comments_count"));
+    assert_eq!(request.validate_with(&options), Ok(()));
+
+    let request = SearchRequest::new().collapse(Collapse::new("comments.author"));
+    assert_eq!(request.validate(), Ok(()));
+    assert_eq!(
+        request.validate_with(&options),
+        Err(ValidationError::CollapseOnNestedField {
+            field: "comments.author".to_string(),
+            nested_path: "comments".to_string(),
+        })
+    );
+
+    // Second-level collapses inside inner hits are checked too
+    let request =
+        SearchRequest::new()
+            .collapse(Collapse::new("user_id").inner_hits(
+                InnerHits::new("by_author").collapse(Collapse::new("comments.author")),
+            ));
+    assert_eq!(
+        request.validate_with(&options),
+        Err(ValidationError::CollapseOnNestedField {
+            field: "comments.author".to_string(),
+            nested_path: "comments".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_fields() {
+    let request = SearchRequest::new().fields([
+        FieldAndFormat::new("title"),
+        FieldAndFormat::new("published_at").format("yyyy-MM-dd"),
+    ]);
+
+    assert_eq!(
+        request.to_json()["fields"],
+        serde_json::json!(["title", { "field": "published_at", "format": "yyyy-MM-dd" }])
+    );
+
+    let mut builder = SearchRequestBuilder::new();
+    builder
+        .add_field("title")
+        .add_field(FieldAndFormat::new("published_at").format("epoch_millis"));
+    assert_eq!(
+        builder.build().to_json()["fields"],
+        serde_json::json!(["title", { "field": "published_at", "format": "epoch_millis" }])
+    );
+
+    assert!(SearchRequest::new().to_json().get("fields").is_none());
+}
+
+#[test]
+fn test_runtime_mappings() {
+    let day_of_week = serde_json::json!({
+        "type": "keyword",
+        "script": {
+            "source": "emit(doc['published_at'].value.dayOfWeekEnum.toString())"
+        }
+    });
+    let mut runtime_mappings = Map::new();
+    runtime_mappings.insert("day_of_week".to_string(), day_of_week.clone());
+
+    let request = SearchRequest::new()
+        .runtime_mappings(runtime_mappings)
+        .query(QueryType::term("day_of_week", "MONDAY"))
+        .fields(["day_of_week"]);
+    let json = request.to_json();
+
+    assert_eq!(json["runtime_mappings"]["day_of_week"], day_of_week);
+    assert_eq!(json["fields"], serde_json::json!(["day_of_week"]));
+    assert_eq!(
+        json["query"],
+        serde_json::json!({ "term": { "day_of_week": "MONDAY" } })
+    );
+}
+
+#[test]
+fn test_add_global_facet() {
+    let request = SearchRequest::new()
+        .query(QueryType::term("status", "active"))
+        .agg(
+            "tags",
+            AggregationType::Terms(TermsAggregation::new("tags").size(25)),
+        )
+        .add_global_facet("all_tags", "tags", 25);
+
+    assert_eq!(
+        request.to_json()["aggs"],
+        serde_json::json!({
+            "tags": { "terms": { "field": "tags", "size": 25 } },
+            "all_tags": {
+                "global": {},
+                "aggs": {
+                    "all_tags": { "terms": { "field": "tags", "size": 25 } }
+                }
+            }
+        })
+    );
+    // The facet's explicit size doesn't trip the default terms size warning
+    assert_eq!(request.warnings(), vec![]);
+
+    let mut builder = SearchRequestBuilder::from_request(&request);
+    builder
+        .remove_agg("all_tags")
+        .add_global_facet("all_tags", "tags", 25);
+    assert_eq!(builder.build().to_json(), request.to_json());
+}
+
+#[test]
+fn test_to_json_bytes() {
+    let request = SearchRequest::new()
+        .query(QueryType::term("status", "active"))
+        .size(20)
+        .sort(SortType::field_desc("timestamp"));
+
+    let bytes = request.to_json_bytes();
+    assert_eq!(
+        serde_json::from_slice::<Value>(&bytes).unwrap(),
+        request.to_json()
+    );
+
+    let mut written = Vec::new();
+    request.to_json_writer(&mut written).unwrap();
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_validate_agg_names() {
+    let terms = || AggregationType::Terms(TermsAggregation::new("tags"));
+
+    assert_eq!(
+        SearchRequest::new().agg("top_tags", terms()).validate(),
+        Ok(())
+    );
+    // OpenSearch allows whitespace in names
+    assert_eq!(
+        SearchRequest::new().agg("top tags", terms()).validate(),
+        Ok(())
+    );
+    assert_eq!(
+        SearchRequest::new().agg("top[tags]", terms()).validate(),
+        Err(ValidationError::InvalidAggName {
+            name: "top[tags]".to_string(),
+        })
+    );
+
+    // Sub-aggregations are checked too
+    let nested = AggregationType::Terms(TermsAggregation::new("tags").sub_agg("by>host", terms()));
+    assert_eq!(
+        SearchRequest::new().agg("tags", nested).validate(),
+        Err(ValidationError::InvalidAggName {
+            name: "by>host".to_string(),
+        })
+    );
+
+    // As are the names a pipeline aggregation references, metric suffixes aside
+    let histogram = |path: &'static str| {
+        AggregationType::DateHistogram(
+            DateHistogramAggregation::calendar("timestamp", "month")
+                .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+                .sub_agg(
+                    "running",
+                    AggregationType::CumulativeSum(CumulativeSumAggregation::new(path)),
+                ),
+        )
+    };
+    assert_eq!(
+        SearchRequest::new()
+            .agg("per_month", histogram("sales.value"))
+            .validate(),
+        Ok(())
+    );
+    assert_eq!(
+        SearchRequest::new()
+            .agg("per_month", histogram("total]sales"))
+            .validate(),
+        Err(ValidationError::InvalidAggName {
+            name: "total]sales".to_string(),
+        })
+    );
+
+    // Bucket sort paths are checked the same way
+    let sorted = |path: &'static str| {
+        AggregationType::DateHistogram(
+            DateHistogramAggregation::calendar("timestamp", "month")
+                .sub_agg("sales", AggregationType::Sum(SumAggregation::new("price")))
+                .sub_agg(
+                    "top_months",
+                    AggregationType::BucketSort(
+                        BucketSortAggregation::new().sort(SortType::field_desc(path)),
+                    ),
+                ),
+        )
+    };
+    assert_eq!(
+        SearchRequest::new()
+            .agg("per_month", sorted("sales"))
+            .validate(),
+        Ok(())
+    );
+    assert_eq!(
+        SearchRequest::new()
+            .agg("per_month", sorted("sales]"))
+            .validate(),
+        Err(ValidationError::InvalidAggName {
+            name: "sales]".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_warn_on_default_terms_size() {
+    let mut builder = SearchRequestBuilder::new();
+    builder.add_agg(
+        "tags",
+        AggregationType::Terms(TermsAggregation::new("tags").sub_agg(
+            "authors",
+            AggregationType::Terms(TermsAggregation::new("author").size(5)),
+        )),
+    );
+
+    // A warning doesn't stop the request from building
+    let request = builder.try_build().unwrap();
+    assert_eq!(
+        request.warnings(),
+        vec![ValidationWarning::DefaultTermsSize {
+            name: "tags".to_string(),
+        }]
+    );
+
+    let sized = SearchRequest::new().agg(
+        "tags",
+        AggregationType::Terms(TermsAggregation::new("tags").size(50)),
+    );
+    assert_eq!(sized.warnings(), vec![]);
+
+    // Warnings are sorted by name whatever order the aggregations are stored in
+    let request = ["status", "authors", "tags", "category"]
+        .into_iter()
+        .fold(SearchRequest::new(), |request, name| {
+            request.agg(name, AggregationType::Terms(TermsAggregation::new(name)))
+        });
+    let names: Vec<String> = request
+        .warnings()
+        .into_iter()
+        .map(|warning| match warning {
+            ValidationWarning::DefaultTermsSize { name } => name,
+        })
+        .collect();
+    assert_eq!(names, ["authors", "category", "status", "tags"]);
+}
+
+#[test]
+fn test_map_fields() {
+    let request = SearchRequest::new()
+        .query(QueryType::term("status", "active"))
+        .sort(SortType::field_desc("published_at"))
+        .agg(
+            "per_month",
+            AggregationType::DateHistogram(
+                DateHistogramAggregation::calendar("published_at", "month").sub_agg(
+                    "tags",
+                    AggregationType::Terms(TermsAggregation::new("tags").size(5)),
+                ),
+            ),
+        );
+
+    let prefixed = request.map_fields(&|field| format!("tenant1.{field}"));
+
+    assert_eq!(
+        prefixed.to_json(),
+        serde_json::json!({
+            "query": {"term": {"tenant1.status": "active"}},
+            "sort": [{"tenant1.published_at": "desc"}],
+            "aggs": {
+                "per_month": {
+                    "date_histogram": {
+                        "field": "tenant1.published_at",
+                        "calendar_interval": "month"
+                    },
+                    "aggs": {
+                        "tags": {"terms": {"field": "tenant1.tags", "size": 5}}
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(
+        request.to_json()["query"],
+        serde_json::json!({"term": {"status": "active"}})
+    );
+}
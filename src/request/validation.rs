@@ -0,0 +1,332 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::{
+    AggregationType, Collapse, DistanceUnit, HighlightType, SearchRequest, SearchRequestBuilder,
+    SortType,
+};
+
+/// The size OpenSearch uses when a request doesn't set one
+const DEFAULT_SIZE: u32 = 10;
+
+/// The number of buckets OpenSearch returns when a terms aggregation doesn't set a size
+const DEFAULT_TERMS_SIZE: u32 = 10;
+
+/// A problem with a search request that OpenSearch would reject
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// `from + size` is past the index's `max_result_window`, use `search_after` to page deeper
+    DeepPagination {
+        /// The requested offset
+        from: u32,
+        /// The requested number of results
+        size: u32,
+        /// The limit `from + size` must stay within
+        max_result_window: u32,
+    },
+    /// A geo distance sort has a unit OpenSearch doesn't know
+    InvalidDistanceUnit {
+        /// The field being sorted on
+        field: String,
+        /// The rejected unit
+        unit: String,
+    },
+    /// The collapse field is inside a nested object, which can't be collapsed on. Collapse on a
+    /// top-level field and use inner hits to expand each group instead
+    CollapseOnNestedField {
+        /// The collapse field
+        field: String,
+        /// The declared nested path the field is under
+        nested_path: String,
+    },
+    /// An aggregation name, or a name referenced by a pipeline aggregation's `buckets_path`,
+    /// contains a character OpenSearch doesn't allow
+    InvalidAggName {
+        /// The rejected name
+        name: String,
+    },
+    /// A highlighted field uses a highlighter OpenSearch doesn't ship
+    UnknownHighlightType {
+        /// The highlighted field
+        field: String,
+        /// The rejected highlighter
+        highlight_type: String,
+    },
+    /// A boost was set on a query type that has no boost, e.g. `regexp`
+    BoostUnsupported {
+        /// The query type, e.g. `regexp`
+        query: &'static str,
+    },
+    /// A float is NaN or infinite, which JSON can't represent
+    NonFiniteFloat {
+        /// The name of the rejected value, e.g. `boost`
+        name: &'static str,
+        /// The rejected value
+        value: f64,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::DeepPagination {
+                from,
+                size,
+                max_result_window,
+            } => write!(
+                f,
+                "from ({from}) + size ({size}) exceeds max_result_window ({max_result_window}), \
+                 use search_after to page deeper"
+            ),
+            ValidationError::InvalidDistanceUnit { field, unit } => {
+                write!(
+                    f,
+                    "unknown distance unit {unit:?} in geo distance sort on {field:?}"
+                )
+            }
+            ValidationError::CollapseOnNestedField { field, nested_path } => write!(
+                f,
+                "can't collapse on {field:?}, it is inside the nested path {nested_path:?}"
+            ),
+            ValidationError::InvalidAggName { name } => write!(
+                f,
+                "invalid aggregation name {name:?}, names can't be empty or contain '[', ']' \
+                 or '>'"
+            ),
+            ValidationError::UnknownHighlightType {
+                field,
+                highlight_type,
+            } => write!(
+                f,
+                "unknown highlight type {highlight_type:?} on {field:?}, \
+                 expected unified, plain or fvh"
+            ),
+            ValidationError::BoostUnsupported { query } => {
+                write!(f, "{query} queries don't take a boost")
+            }
+            ValidationError::NonFiniteFloat { name, value } => {
+                write!(f, "{name} must be a finite number, got {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A likely mistake in a search request that OpenSearch accepts, reported by
+/// [`SearchRequest::warnings`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ValidationWarning {
+    /// A terms aggregation has no explicit `size`, so OpenSearch silently returns only the top
+    /// 10 buckets
+    DefaultTermsSize {
+        /// The name of the terms aggregation
+        name: String,
+    },
+}
+
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationWarning::DefaultTermsSize { name } => write!(
+                f,
+                "terms aggregation {name:?} has no size, the default of {DEFAULT_TERMS_SIZE} may \
+                 truncate its buckets"
+            ),
+        }
+    }
+}
+
+/// Reject NaN and infinite values for the `try_` setters
+pub(crate) fn require_finite(name: &'static str, value: f64) -> Result<f64, ValidationError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(ValidationError::NonFiniteFloat { name, value })
+    }
+}
+
+/// Limits a search request is validated against
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    /// The index's `index.max_result_window`
+    pub max_result_window: u32,
+    /// The index's nested object paths, used to reject collapsing on a nested field
+    pub nested_paths: Vec<String>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            max_result_window: 10_000,
+            nested_paths: Vec::new(),
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// Create ValidationOptions with OpenSearch's defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index's `index.max_result_window`
+    pub fn max_result_window(mut self, max_result_window: u32) -> Self {
+        self.max_result_window = max_result_window;
+        self
+    }
+
+    /// Declare a nested object path in the index mapping (can be called multiple times)
+    pub fn nested_path(mut self, nested_path: impl Into<String>) -> Self {
+        self.nested_paths.push(nested_path.into());
+        self
+    }
+}
+
+impl<'a> SearchRequest<'a> {
+    /// Check the request for problems OpenSearch would reject, using the default limits
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    /// Check the request for problems OpenSearch would reject
+    pub fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        let from = self.from.unwrap_or(0);
+        let size = self.size.unwrap_or(DEFAULT_SIZE);
+        if from.saturating_add(size) > options.max_result_window {
+            return Err(ValidationError::DeepPagination {
+                from,
+                size,
+                max_result_window: options.max_result_window,
+            });
+        }
+
+        validate_agg_names(&self.aggs)?;
+
+        if let Some(ref collapse) = self.collapse {
+            validate_collapse(collapse, options)?;
+        }
+
+        if let Some(ref highlight) = self.highlight {
+            for (field, highlight_field) in &highlight.fields {
+                if let Some(HighlightType::Other(ref other)) = highlight_field.highlight_type {
+                    return Err(ValidationError::UnknownHighlightType {
+                        field: field.to_string(),
+                        highlight_type: other.to_string(),
+                    });
+                }
+            }
+        }
+
+        for sort in self.sort.iter() {
+            if let SortType::GeoDistance(geo_distance) = sort
+                && let Some(ref unit) = geo_distance.unit
+                && DistanceUnit::parse(unit).is_none()
+            {
+                return Err(ValidationError::InvalidDistanceUnit {
+                    field: geo_distance.field.to_string(),
+                    unit: unit.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List likely mistakes that OpenSearch accepts but that probably don't do what was meant.
+    /// Unlike [`SearchRequest::validate`] these don't stop the request from being sent
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        collect_agg_warnings(&self.aggs, &mut warnings);
+        // Aggregations are kept in a HashMap, sort so the order doesn't change between runs
+        warnings.sort();
+        warnings
+    }
+}
+
+fn collect_agg_warnings(
+    aggs: &HashMap<Cow<'_, str>, AggregationType<'_>>,
+    warnings: &mut Vec<ValidationWarning>,
+) {
+    for (name, agg) in aggs {
+        if let AggregationType::Terms(terms) = agg
+            && terms.size.is_none()
+        {
+            warnings.push(ValidationWarning::DefaultTermsSize {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(sub_aggs) = agg.sub_aggs() {
+            collect_agg_warnings(sub_aggs, warnings);
+        }
+    }
+}
+
+/// Check the collapse field and the second-level collapses of its inner hits aren't inside a
+/// nested object
+fn validate_collapse(
+    collapse: &Collapse<'_>,
+    options: &ValidationOptions,
+) -> Result<(), ValidationError> {
+    if let Some(nested_path) = options.nested_paths.iter().find(|path| {
+        collapse
+            .field
+            .strip_prefix(path.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }) {
+        return Err(ValidationError::CollapseOnNestedField {
+            field: collapse.field.to_string(),
+            nested_path: nested_path.clone(),
+        });
+    }
+
+    for inner_hits in &collapse.inner_hits {
+        if let Some(ref collapse) = inner_hits.collapse {
+            validate_collapse(collapse, options)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check the names of `aggs`, their sub-aggregations and the names their pipeline aggregations
+/// reference
+fn validate_agg_names(
+    aggs: &HashMap<Cow<'_, str>, AggregationType<'_>>,
+) -> Result<(), ValidationError> {
+    for (name, agg) in aggs {
+        validate_agg_name(name)?;
+
+        // Each `>` separated step names an aggregation, optionally followed by a metric
+        for path in agg.buckets_paths() {
+            for step in path.split('>') {
+                let referenced = step.split(['.', '[']).next().unwrap_or_default();
+                validate_agg_name(referenced)?;
+            }
+        }
+
+        if let Some(sub_aggs) = agg.sub_aggs() {
+            validate_agg_names(sub_aggs)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_agg_name(name: &str) -> Result<(), ValidationError> {
+    if name.is_empty() || name.contains(['[', ']', '>']) {
+        return Err(ValidationError::InvalidAggName {
+            name: name.to_string(),
+        });
+    }
+    Ok(())
+}
+
+impl<'a> SearchRequestBuilder<'a> {
+    /// Build the final SearchRequest, validating it with the default limits
+    pub fn try_build(self) -> Result<SearchRequest<'a>, ValidationError> {
+        let request = self.build();
+        request.validate()?;
+        Ok(request)
+    }
+}
@@ -0,0 +1,164 @@
+use serde::Serialize;
+
+use super::*;
+
+fn assert_serialize_parity<T: Serialize + ToOpenSearchJson + std::fmt::Debug>(value: &T) {
+    assert_eq!(
+        serde_json::to_value(value).unwrap(),
+        value.to_json(),
+        "serde output diverges from to_json for {value:?}"
+    );
+}
+
+fn queries() -> Vec<QueryType<'static>> {
+    vec![
+        QueryType::Bool(
+            BoolQuery::new()
+                .must(QueryType::term("status", "active"))
+                .minimum_should_match(1),
+        ),
+        QueryType::constant_score(QueryType::term("status", "active")),
+        QueryType::DisMax(
+            DisMaxQuery::new()
+                .query(QueryType::term("status", "active"))
+                .tie_breaker(0.3),
+        ),
+        QueryType::FunctionScore(FunctionScoreQuery::new().query(QueryType::match_all())),
+        QueryType::from(GeoDistanceQuery::new(
+            "location",
+            GeoPoint::new(40.7, -74.0),
+            Distance::new(12.0, DistanceUnit::Kilometers),
+        )),
+        QueryType::from(HasChildQuery::new("answer", QueryType::match_all()).score_mode("max")),
+        QueryType::from(HasParentQuery::new("question", QueryType::match_all()).score(true)),
+        QueryType::match_all().with_boost(1.2),
+        QueryType::match_none(),
+        QueryType::match_phrase("title", "quick fox"),
+        QueryType::match_phrase_prefix("title", "quick f"),
+        QueryType::Match(MatchQuery::new("title", "fox")),
+        QueryType::multi_match("fox", ["title^2", "body"]).with_boost(1.5),
+        QueryType::nested("comments", QueryType::term("comments.author", "alice")),
+        QueryType::query_string("title:fox"),
+        QueryType::range_between("age", 18, 65),
+        QueryType::regexp("name", "jo.*"),
+        QueryType::term("status", "active"),
+        QueryType::terms("tags", ["a", "b"]),
+        QueryType::wildcard("name", "jo*", true),
+        QueryType::raw(serde_json::json!({"exists": {"field": "title"}})),
+    ]
+}
+
+fn sorts() -> Vec<SortType<'static>> {
+    vec![
+        SortType::Field(FieldSort::new("timestamp", SortOrder::Desc)),
+        SortType::Score,
+        SortType::Doc,
+        SortType::ScoreWithOrder(ScoreWithOrderSort::new(SortOrder::Asc)),
+        SortType::ScriptSort(ScriptSort::new(
+            Script::new("doc['price'].value"),
+            ScriptSortType::Number,
+            SortOrder::Asc,
+        )),
+        SortType::GeoDistance(
+            GeoDistanceSort::new("location", GeoPoint::new(40.7, -74.0), SortOrder::Asc)
+                .unit(DistanceUnit::Kilometers),
+        ),
+    ]
+}
+
+fn aggregations() -> Vec<AggregationType<'static>> {
+    vec![
+        AggregationType::Terms(TermsAggregation::new("category").size(10)),
+        AggregationType::Cardinality(CardinalityAggregation::new("user_id")),
+        AggregationType::ValueCount(ValueCountAggregation::new("user_id")),
+        AggregationType::PercentileRanks(PercentileRanksAggregation::new("load_time", [500.0])),
+        AggregationType::TopMetrics(TopMetricsAggregation::new(["price"], SortType::Score)),
+        AggregationType::AdjacencyMatrix(
+            AdjacencyMatrixAggregation::new().filter("a", QueryType::term("group", "a")),
+        ),
+        AggregationType::SignificantTerms(SignificantTermsAggregation::new("tags")),
+        AggregationType::Sampler(SamplerAggregation::new().shard_size(100)),
+        AggregationType::GeoDistance(
+            GeoDistanceAggregation::new("location", GeoPoint::new(40.7, -74.0))
+                .range(RangeBucket::new().to(10.0))
+                .range(RangeBucket::new().from(10.0))
+                .unit(DistanceUnit::Kilometers),
+        ),
+        AggregationType::GeohashGrid(GeohashGridAggregation::new("location").precision(5)),
+        AggregationType::GeoCentroid(GeoCentroidAggregation::new("location")),
+        AggregationType::AutoDateHistogram(
+            AutoDateHistogramAggregation::new("timestamp", 30).time_zone("Europe/Amsterdam"),
+        ),
+        AggregationType::Sum(SumAggregation::new("price")),
+        AggregationType::DateHistogram(
+            DateHistogramAggregation::calendar("timestamp", "1d").min_doc_count(0),
+        ),
+        AggregationType::CumulativeSum(CumulativeSumAggregation::new("sales")),
+        AggregationType::Derivative(DerivativeAggregation::new("sales").unit("1d")),
+        AggregationType::MovingFn(MovingFnAggregation::moving_avg("sales", 5)),
+        AggregationType::BucketSelector(
+            BucketSelectorAggregation::new("params.count > 100").buckets_path("count", "_count"),
+        ),
+        AggregationType::BucketSort(
+            BucketSortAggregation::new()
+                .sort(SortType::field_desc("sales"))
+                .size(3)
+                .gap_policy(GapPolicy::InsertZeros),
+        ),
+        AggregationType::Filters(FiltersAggregation::new(AggregationFilters::named([
+            ("errors", QueryType::term("level", "error")),
+            ("warnings", QueryType::term("level", "warning")),
+        ]))),
+        AggregationType::Filters(FiltersAggregation::new(AggregationFilters::anonymous([
+            QueryType::term("level", "error"),
+        ]))),
+        AggregationType::Global(GlobalAggregation::new().sub_agg(
+            "all_tags",
+            AggregationType::Terms(TermsAggregation::new("tags")),
+        )),
+        AggregationType::TopHits(TopHitsAggregation::new().size(3)),
+    ]
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema_lists_every_query_kind() {
+    let schema = SearchRequest::json_schema();
+    let kinds = &schema["properties"]["query"]["properties"];
+
+    // queries() holds every variant for the serialize parity tests. Raw queries can be
+    // anything, so they aren't listed
+    for query in queries().iter().filter(|q| !matches!(q, QueryType::Raw(_))) {
+        let kind = query.kind_name();
+        assert!(
+            kinds[kind].is_object(),
+            "{kind} is missing from the query schema"
+        );
+    }
+}
+
+#[test]
+fn test_query_serialize_parity() {
+    for query in queries() {
+        assert_serialize_parity(&query);
+    }
+}
+
+#[test]
+fn test_sort_serialize_parity() {
+    for sort in sorts() {
+        assert_serialize_parity(&sort);
+    }
+}
+
+#[test]
+fn test_aggregation_serialize_parity() {
+    for agg in aggregations() {
+        assert_serialize_parity(&agg);
+    }
+
+    let nested = AggregationType::Sampler(
+        SamplerAggregation::new().sub_agg("group", AggregationType::group_by("author", 2)),
+    );
+    assert_serialize_parity(&nested);
+}
@@ -4,3 +4,8 @@
 pub fn is_empty_slice<T: Clone>(slice: &[T]) -> bool {
     slice.is_empty()
 }
+
+/// JSON has no representation for NaN or infinity, so non-finite floats are dropped.
+pub fn finite(value: f64) -> Option<f64> {
+    value.is_finite().then_some(value)
+}
@@ -0,0 +1,130 @@
+//! Validates generated queries against a live OpenSearch node with `_validate/query`.
+//!
+//! Start a node with `docker compose up -d` and run with
+//! `cargo test --features integration -- --ignored`. Set `OPENSEARCH_URL` to point at a node
+//! other than `http://localhost:9200`.
+#![cfg(feature = "integration")]
+
+use opensearch_query_builder::*;
+use serde_json::{Value, json};
+
+const INDEX: &str = "opensearch-query-builder-validate";
+
+fn opensearch_url() -> String {
+    std::env::var("OPENSEARCH_URL").unwrap_or_else(|_| "http://localhost:9200".to_string())
+}
+
+/// Create the index the queries are validated against, so field and nested path lookups resolve
+fn create_index(client: &reqwest::blocking::Client) {
+    let response = client
+        .put(format!("{}/{INDEX}", opensearch_url()))
+        .header("Content-Type", "application/json")
+        .body(
+            json!({
+                "mappings": {
+                    "properties": {
+                        "title": { "type": "text" },
+                        "status": { "type": "keyword" },
+                        "published_at": { "type": "date" },
+                        "popularity": { "type": "long" },
+                        "comments": {
+                            "type": "nested",
+                            "properties": {
+                                "author": { "type": "keyword" }
+                            }
+                        }
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .send()
+        .expect("OpenSearch should be reachable");
+
+    // A previous run may have already created the index
+    let status = response.status();
+    let body = json_body(response);
+    assert!(
+        status.is_success() || body["error"]["type"] == "resource_already_exists_exception",
+        "failed to create index: {body}"
+    );
+}
+
+fn json_body(response: reqwest::blocking::Response) -> Value {
+    serde_json::from_str(&response.text().expect("response should have a body"))
+        .expect("response should be JSON")
+}
+
+/// `_validate/query` only accepts the `query` section, so the rest of the request is dropped
+fn assert_valid(request: &SearchRequest) {
+    let client = reqwest::blocking::Client::new();
+    create_index(&client);
+
+    let body = json!({ "query": request.to_json()["query"] });
+    let response = client
+        .post(format!(
+            "{}/{INDEX}/_validate/query?explain=true",
+            opensearch_url()
+        ))
+        .header("Content-Type", "application/json")
+        .body(body.to_string())
+        .send()
+        .expect("OpenSearch should be reachable");
+    let response = json_body(response);
+
+    assert_eq!(response["valid"], true, "{body} is not valid: {response}");
+}
+
+#[test]
+#[ignore = "needs a running OpenSearch node"]
+fn test_bool_query_is_valid() {
+    assert_valid(
+        &SearchRequest::new().query(
+            BoolQuery::new()
+                .must(QueryType::Match(MatchQuery::new("title", "fox")))
+                .filter(QueryType::term("status", "published"))
+                .must_not(QueryType::range_between(
+                    "published_at",
+                    "2020-01-01",
+                    "2020-12-31",
+                ))
+                .should(QueryType::wildcard("status", "draft*", true))
+                .minimum_should_match(1)
+                .into(),
+        ),
+    );
+}
+
+#[test]
+#[ignore = "needs a running OpenSearch node"]
+fn test_function_score_query_is_valid() {
+    assert_valid(
+        &SearchRequest::new().query(QueryType::FunctionScore(
+            FunctionScoreQuery::new()
+                .query(QueryType::Match(MatchQuery::new("title", "fox")))
+                .function(ScoreFunction {
+                    function: ScoreFunctionType::FieldValueFactor(FieldValueFactor::new(
+                        "popularity",
+                    )),
+                    filter: None,
+                    weight: Some(2.0),
+                }),
+        )),
+    );
+}
+
+#[test]
+#[ignore = "needs a running OpenSearch node"]
+fn test_nested_query_is_valid() {
+    assert_valid(&SearchRequest::new().query(QueryType::nested(
+        "comments",
+        QueryType::term("comments.author", "alice"),
+    )));
+}
+
+#[test]
+#[ignore = "needs a running OpenSearch node"]
+fn test_autocomplete_and_fuzzy_search_are_valid() {
+    assert_valid(&SearchRequest::new().query(QueryType::autocomplete("qui", &[("title", 2.0)])));
+    assert_valid(&SearchRequest::new().query(QueryType::fuzzy_search("quikc", ["title"], "AUTO")));
+}